@@ -0,0 +1,240 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{
+    build_system::{test_filter, BuildCommand, BuildSystem},
+    config::BuildConfig,
+    error::{MutantKrakenError, Result},
+};
+
+/// Builds projects with the commands set in the `build` section of the config.
+/// The commands are run with the shell of the OS
+pub struct CustomBuild {
+    config: BuildConfig,
+}
+
+impl CustomBuild {
+    pub fn new(config: BuildConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl BuildSystem for CustomBuild {
+    fn name(&self) -> &'static str {
+        "custom build"
+    }
+
+    fn validate(&self, project_path: &Path) -> Result<()> {
+        let working_dir = self.working_dir(project_path);
+        if !working_dir.is_dir() {
+            return Err(MutantKrakenError::Error(format!(
+                "The build working_dir {} does not exist",
+                working_dir.display()
+            )));
+        }
+        Ok(())
+    }
+
+    fn working_dir(&self, project_path: &Path) -> PathBuf {
+        match &self.config.working_dir {
+            Some(working_dir) => project_path.join(working_dir),
+            None => project_path.to_path_buf(),
+        }
+    }
+
+    fn command(&self, _project_path: &Path, command: &BuildCommand) -> Command {
+        let command = match command {
+            BuildCommand::Assemble(_) => self.config.build_command.clone(),
+            // Projects without a clean command run an empty command, which always succeeds
+            BuildCommand::Clean => self.config.clean_command.clone().unwrap_or_default(),
+            // The test command has no placeholder for test methods, so the tests of the file are run.
+            // The filter is empty when every test is run
+            BuildCommand::Test(file) | BuildCommand::TestMethods(file, _) => {
+                self.config.test_command.replace(
+                    "{test_filter}",
+                    &shell_quote(file.and_then(test_filter).unwrap_or_default()),
+                )
+            }
+        };
+        shell_command(&command)
+    }
+}
+
+/// Quotes `value` so the shell of the OS passes it to the command as a single argument
+fn shell_quote(value: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", value)
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+/// Creates a command that runs `command` with the shell of the OS
+fn shell_command(command: &str) -> Command {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    } else if cfg!(unix) {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    } else {
+        panic!("Unsupported OS");
+    };
+    cmd.arg(command);
+    cmd
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::build_system::{run, test_util::TempDir};
+    use crate::mutation_tool::{Mutation, MutationOperators, MutationResult};
+    use std::fs;
+
+    const KOTLIN_CODE_DIR: &str = "./tests/kotlin-test-projects/no-gradle-project";
+
+    fn build_config(working_dir: Option<&str>) -> BuildConfig {
+        BuildConfig {
+            // The build fails when the file has an unbalanced parenthesis
+            build_command:
+                "test $(tr -cd '(' < Calculator.kt | wc -c) = $(tr -cd ')' < Calculator.kt | wc -c)"
+                    .into(),
+            // The tests fail when the file no longer adds a and b
+            test_command: "grep -q 'result = a + b' {test_filter}.kt".into(),
+            clean_command: Some("rm -rf out".into()),
+            working_dir: working_dir.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_custom_build_commands() {
+        let build = CustomBuild::new(build_config(None));
+        let args = |command: BuildCommand| {
+            build
+                .command(Path::new("."), &command)
+                .get_args()
+                .last()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(args(BuildCommand::Clean), "rm -rf out");
+        assert_eq!(
+            build.working_dir(Path::new("project")),
+            PathBuf::from("project")
+        );
+        assert_eq!(
+            CustomBuild::new(build_config(Some("app"))).working_dir(Path::new("project")),
+            PathBuf::from("project/app")
+        );
+        assert!(CustomBuild::new(build_config(Some("missing")))
+            .validate(Path::new(KOTLIN_CODE_DIR))
+            .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_custom_build_quotes_test_filter() {
+        let project_path = TempDir::new("custom_build_filter");
+        let build = CustomBuild::new(BuildConfig {
+            // Writes each argument the filter becomes on its own line
+            test_command: "printf '%s\\n' {test_filter} > filter".into(),
+            ..build_config(None)
+        });
+        let filter = |file: Option<&str>| {
+            let child = build.test(&project_path, file.map(Path::new)).unwrap();
+            assert!(build.wait(child).unwrap());
+            fs::read_to_string(project_path.join("filter")).unwrap()
+        };
+        assert_eq!(filter(Some("src/Calculator.kt")), "Calculator\n");
+        // The name is one argument, which the shell does not split or expand
+        assert_eq!(
+            filter(Some("src/My *Calculator's.kt")),
+            "My *Calculator's\n"
+        );
+        // The filter is empty when every test is run
+        assert_eq!(filter(None), "\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_custom_build_runs_mutations() {
+        let original = fs::read_to_string(format!("{}/Calculator.kt", KOTLIN_CODE_DIR)).unwrap();
+        let project_path = TempDir::new("custom_build_project");
+        let original_file_path = project_path.join("Calculator.kt");
+        let mutated_file_path = project_path.join("Mutated.kt");
+        let cleaned_path = project_path.join("cleaned");
+        let build = CustomBuild::new(BuildConfig {
+            clean_command: Some("echo . >> cleaned".into()),
+            ..build_config(None)
+        });
+        build.prepare_worker(&project_path).unwrap();
+
+        for (old_op, new_op, expected) in [
+            ("result = a + b", "result = a - b", MutationResult::Killed),
+            ("result = a * b", "result = a / b", MutationResult::Survived),
+            (
+                "fun calculate(",
+                "fun calculate",
+                MutationResult::BuildFailed,
+            ),
+        ] {
+            fs::write(&original_file_path, &original).unwrap();
+            fs::write(&mutated_file_path, original.replacen(old_op, new_op, 1)).unwrap();
+            let mut mutation = Mutation::new(
+                0,
+                0,
+                new_op.into(),
+                old_op.into(),
+                0,
+                MutationOperators::ArithmeticReplacementOperator,
+                "Calculator.kt".into(),
+            );
+            run(
+                &build,
+                &project_path,
+                &mutated_file_path,
+                &original_file_path,
+                None,
+                &mut mutation,
+            )
+            .unwrap();
+            assert_eq!(mutation.result, expected, "Failed for: {}", new_op);
+        }
+        // The project is only cleaned once, before the first mutant
+        assert_eq!(
+            fs::read_to_string(&cleaned_path).unwrap().lines().count(),
+            1
+        );
+
+        // A mutant that no test covers survives without running the tests
+        fs::write(&original_file_path, &original).unwrap();
+        fs::write(
+            &mutated_file_path,
+            original.replacen("result = a + b", "result = a - b", 1),
+        )
+        .unwrap();
+        let mut mutation = Mutation::new(
+            0,
+            0,
+            "result = a - b".into(),
+            "result = a + b".into(),
+            0,
+            MutationOperators::ArithmeticReplacementOperator,
+            "Calculator.kt".into(),
+        );
+        run(
+            &build,
+            &project_path,
+            &mutated_file_path,
+            &original_file_path,
+            Some(&[]),
+            &mut mutation,
+        )
+        .unwrap();
+        assert_eq!(mutation.result, MutationResult::Survived);
+    }
+}
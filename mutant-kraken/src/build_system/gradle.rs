@@ -0,0 +1,506 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use regex::Regex;
+
+use crate::{
+    build_system::{script_command, BuildCommand, BuildSystem},
+    error::{MutantKrakenError, Result},
+};
+
+/// A subproject included in the `settings.gradle(.kts)` of a multi-module build
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GradleModule {
+    /// The path of the module, like `:core:data`
+    pub path: String,
+    /// The directory of the module, relative to the root project
+    pub dir: PathBuf,
+    /// The paths of the modules this module depends on
+    pub dependencies: Vec<String>,
+}
+
+/// Builds projects with the Gradle wrapper at the root of the project.
+/// The daemons started in the copy of the project of each worker are stopped when it finishes.
+/// In multi-module builds, only the module of the mutated file and the modules that
+/// depend on it are built and tested
+#[derive(Debug, Default)]
+pub struct Gradle {
+    modules: Vec<GradleModule>,
+}
+
+impl Gradle {
+    /// Creates the build system of the project, with the modules included in its settings file
+    pub fn new(project_path: &Path) -> Self {
+        let settings = ["settings.gradle.kts", "settings.gradle"]
+            .iter()
+            .find_map(|file| fs::read_to_string(project_path.join(file)).ok());
+        let modules = settings
+            .map(|settings| parse_settings(&settings))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(path, dir)| GradleModule {
+                dependencies: read_dependencies(&project_path.join(&dir)),
+                path,
+                dir,
+            })
+            .collect();
+        Self { modules }
+    }
+
+    pub fn modules(&self) -> &[GradleModule] {
+        &self.modules
+    }
+
+    /// Gets the module the file is in. The file is relative to the root project
+    fn module(&self, file: &Path) -> Option<&GradleModule> {
+        self.modules
+            .iter()
+            .filter(|module| file.starts_with(&module.dir))
+            // Modules can be nested in the directory of other modules
+            .max_by_key(|module| module.dir.components().count())
+    }
+
+    /// Gets the module and every module that depends on it, directly or through other modules
+    fn affected_modules<'a>(&'a self, module: &'a GradleModule) -> Vec<&'a str> {
+        let mut affected = vec![module.path.as_str()];
+        let mut next = 0;
+        while next < affected.len() {
+            let dependency = affected[next];
+            for module in self.modules.iter() {
+                if !affected.contains(&module.path.as_str())
+                    && module.dependencies.iter().any(|d| d == dependency)
+                {
+                    affected.push(&module.path);
+                }
+            }
+            next += 1;
+        }
+        affected
+    }
+
+    /// Gets the tasks that run `task` for the file. These are the tasks of the modules
+    /// affected by the file, or the task of the whole build when the file is not in a module
+    fn tasks(&self, task: &str, file: Option<&Path>) -> Vec<String> {
+        match file.and_then(|file| self.module(file)) {
+            Some(module) => self
+                .affected_modules(module)
+                .iter()
+                .map(|module| format!("{}:{}", module, task))
+                .collect(),
+            None => vec![task.to_string()],
+        }
+    }
+}
+
+impl BuildSystem for Gradle {
+    fn name(&self) -> &'static str {
+        "gradle"
+    }
+
+    /// This will check to see if there is a gradlew file in the root of the directory
+    fn validate(&self, project_path: &Path) -> Result<()> {
+        if !project_path.join("gradlew").exists() {
+            return Err(MutantKrakenError::Error(
+                "gradlew does not exist at the root of this project".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Stops the Gradle daemons, which would otherwise keep running after the copy is removed
+    fn finish_worker(&self, project_path: &Path) -> Result<()> {
+        let status = gradlew()
+            .arg("--stop")
+            .current_dir(project_path)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_err(|e| self.error(e))?;
+        if !status.success() {
+            tracing::warn!(
+                "Failed to stop the Gradle daemons of {}",
+                project_path.display()
+            );
+        }
+        Ok(())
+    }
+
+    /// A test task fails when none of its tests match the `--tests` filters
+    fn no_tests_matched(&self, output: &str) -> bool {
+        output.contains("No tests found for given includes")
+    }
+
+    fn module_of(&self, file: &Path) -> Option<String> {
+        self.module(file).map(|module| module.path.clone())
+    }
+
+    fn command(&self, _project_path: &Path, command: &BuildCommand) -> Command {
+        let mut cmd = gradlew();
+        match command {
+            BuildCommand::Assemble(file) => cmd.args(self.tasks("assemble", *file)),
+            BuildCommand::Clean => cmd.arg("clean"),
+            // The tests are not filtered by file, since the tests of a file are not always named after it
+            BuildCommand::Test(file) => cmd.args(self.tasks("test", *file)),
+            BuildCommand::TestMethods(file, tests) => {
+                cmd.args(self.tasks("test", *file));
+                for test in tests.iter() {
+                    cmd.args(["--tests", test]);
+                }
+                &mut cmd
+            }
+        };
+        cmd.arg("--daemon");
+        if *command != BuildCommand::Clean {
+            cmd.args(["--parallel", "--quiet"]);
+        }
+        cmd
+    }
+}
+
+/// Creates a command that runs the Gradle wrapper at the root of the project
+fn gradlew() -> Command {
+    script_command(
+        Some(if cfg!(windows) {
+            "gradlew.bat"
+        } else {
+            "gradlew"
+        }),
+        "gradle",
+    )
+}
+
+/// Gets the path and directory of each module included in a settings file
+fn parse_settings(settings: &str) -> Vec<(String, PathBuf)> {
+    let include = Regex::new(r"\binclude\b\s*(?:\(([^)]*)\)|([^\n]*))").expect("Invalid regex");
+    let quoted = Regex::new(r#"["']([^"']+)["']"#).expect("Invalid regex");
+    let project_dir = Regex::new(
+        r#"project\(\s*["']([^"']+)["']\s*\)\.projectDir\s*=\s*file\(\s*["']([^"']+)["']\s*\)"#,
+    )
+    .expect("Invalid regex");
+
+    let settings = strip_comments(settings);
+    let mut modules = Vec::new();
+    for include in include.captures_iter(&settings) {
+        let arguments = include.get(1).or(include.get(2)).map_or("", |a| a.as_str());
+        for name in quoted.captures_iter(arguments) {
+            let path = module_path(&name[1]);
+            // The directory of `:core:data` is `core/data`
+            let dir = path.split(':').filter(|part| !part.is_empty()).collect();
+            modules.push((path, dir));
+        }
+    }
+    // Modules can be moved to another directory
+    for project_dir in project_dir.captures_iter(&settings) {
+        let path = module_path(&project_dir[1]);
+        if let Some(module) = modules.iter_mut().find(|(p, _)| *p == path) {
+            module.1 = PathBuf::from(&project_dir[2]);
+        }
+    }
+    modules
+}
+
+/// Gets the paths of the modules the build file of the module depends on
+fn read_dependencies(module_dir: &Path) -> Vec<String> {
+    let dependency =
+        Regex::new(r#"\bproject\(\s*(?:path\s*[:=]\s*)?["']([^"']+)["']"#).expect("Invalid regex");
+    let build_file = ["build.gradle.kts", "build.gradle"]
+        .iter()
+        .find_map(|file| fs::read_to_string(module_dir.join(file)).ok())
+        .unwrap_or_default();
+    let mut dependencies = Vec::new();
+    for project in dependency.captures_iter(&strip_comments(&build_file)) {
+        let path = module_path(&project[1]);
+        if !dependencies.contains(&path) {
+            dependencies.push(path);
+        }
+    }
+    dependencies
+}
+
+/// Removes the line comments of a build script
+fn strip_comments(script: &str) -> String {
+    script
+        .lines()
+        .map(|line| match line.find("//") {
+            Some(start) => &line[..start],
+            None => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Gets the full path of a module, which starts with `:`
+fn module_path(name: &str) -> String {
+    if name.starts_with(':') {
+        name.to_string()
+    } else {
+        format!(":{}", name)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::build_system::{run, test_util::TempDir};
+    use crate::mutation_tool::{Mutation, MutationResult};
+    use std::{fs, path::PathBuf};
+    const KOTLIN_CODE_DIR: &str = "./tests/kotlin-test-projects";
+    #[test]
+    #[should_panic(expected = "gradlew does not exist at the root of this project")]
+    fn test() {
+        run(
+            &Gradle::default(),
+            &PathBuf::from(KOTLIN_CODE_DIR).join("no-gradle-project"),
+            &PathBuf::new(),
+            &PathBuf::new(),
+            None,
+            &mut Mutation::new(
+                0,
+                0,
+                "new_op".into(),
+                "old_op".into(),
+                0,
+                crate::mutation_tool::MutationOperators::ArithmeticReplacementOperator,
+                "file_name".into(),
+            ),
+        )
+        .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gradle_commands() {
+        let args = |command: BuildCommand| {
+            let cmd = Gradle::default().command(Path::new("."), &command);
+            assert_eq!(cmd.get_program(), "./gradlew");
+            cmd.get_args()
+                .map(|a| a.to_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            args(BuildCommand::Assemble(None)),
+            ["assemble", "--daemon", "--parallel", "--quiet"]
+        );
+        assert_eq!(args(BuildCommand::Clean), ["clean", "--daemon"]);
+        assert_eq!(
+            args(BuildCommand::Test(Some(Path::new(
+                "src/main/kotlin/Calculator.kt"
+            )))),
+            ["test", "--daemon", "--parallel", "--quiet"]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gradle_stops_daemons_of_worker() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let project_path = TempDir::new("gradle_worker_project");
+        let gradlew = project_path.join("gradlew");
+        fs::write(&gradlew, "#!/bin/sh\necho \"$@\" > arguments\n").unwrap();
+        fs::set_permissions(&gradlew, fs::Permissions::from_mode(0o755)).unwrap();
+        Gradle::default().finish_worker(&project_path).unwrap();
+        assert_eq!(
+            fs::read_to_string(project_path.join("arguments")).unwrap(),
+            "--stop\n"
+        );
+    }
+
+    fn multi_module_project() -> TempDir {
+        let project_path = TempDir::new("gradle_multi_module_project");
+        let files = [
+            (
+                "settings.gradle.kts",
+                r#"
+rootProject.name = "shop"
+include(":app", ":core:data")
+include("payments")
+// include(":ignored")
+includeBuild("build-logic")
+project(":payments").projectDir = file("libs/payments")
+"#,
+            ),
+            (
+                "app/build.gradle.kts",
+                r#"
+dependencies {
+    implementation(project(":core:data"))
+    implementation(project(":payments"))
+}
+"#,
+            ),
+            (
+                "libs/payments/build.gradle",
+                "dependencies { implementation project(path: ':core:data') }",
+            ),
+            ("core/data/build.gradle.kts", ""),
+        ];
+        for (file, contents) in files {
+            let path = project_path.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        project_path
+    }
+
+    #[test]
+    fn test_gradle_reads_modules_from_settings() {
+        let gradle = Gradle::new(&multi_module_project());
+        assert_eq!(
+            gradle.modules(),
+            [
+                GradleModule {
+                    path: ":app".into(),
+                    dir: PathBuf::from("app"),
+                    dependencies: vec![":core:data".into(), ":payments".into()],
+                },
+                GradleModule {
+                    path: ":core:data".into(),
+                    dir: PathBuf::from("core/data"),
+                    dependencies: vec![],
+                },
+                GradleModule {
+                    path: ":payments".into(),
+                    dir: PathBuf::from("libs/payments"),
+                    dependencies: vec![":core:data".into()],
+                },
+            ]
+        );
+        assert_eq!(
+            gradle.module_of(Path::new("libs/payments/src/main/kotlin/Card.kt")),
+            Some(":payments".into())
+        );
+        assert_eq!(gradle.module_of(Path::new("buildSrc/Versions.kt")), None);
+        assert!(
+            Gradle::new(Path::new("./tests/kotlin-test-projects/kotlin-project"))
+                .modules()
+                .is_empty()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gradle_runs_tasks_of_affected_modules() {
+        let gradle = Gradle::new(&multi_module_project());
+        let args = |command: BuildCommand| {
+            gradle
+                .command(Path::new("."), &command)
+                .get_args()
+                .map(|a| a.to_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            args(BuildCommand::Test(Some(Path::new(
+                "core/data/src/main/kotlin/Repository.kt"
+            )))),
+            [
+                ":core:data:test",
+                ":app:test",
+                ":payments:test",
+                "--daemon",
+                "--parallel",
+                "--quiet"
+            ]
+        );
+        assert_eq!(
+            args(BuildCommand::Assemble(Some(Path::new(
+                "libs/payments/src/main/kotlin/Card.kt"
+            )))),
+            [
+                ":payments:assemble",
+                ":app:assemble",
+                "--daemon",
+                "--parallel",
+                "--quiet"
+            ]
+        );
+        assert_eq!(
+            args(BuildCommand::Assemble(Some(Path::new(
+                "app/src/main/kotlin/Main.kt"
+            )))),
+            [":app:assemble", "--daemon", "--parallel", "--quiet"]
+        );
+        assert_eq!(
+            args(BuildCommand::TestMethods(
+                Some(Path::new("app/src/main/kotlin/Main.kt")),
+                &[
+                    "com.example.AppTest.testStart".into(),
+                    "com.example.AppTest.test stop".into()
+                ]
+            )),
+            [
+                ":app:test",
+                "--tests",
+                "com.example.AppTest.testStart",
+                "--tests",
+                "com.example.AppTest.test stop",
+                "--daemon",
+                "--parallel",
+                "--quiet"
+            ]
+        );
+        // Every module is tested for the baseline check
+        assert_eq!(
+            args(BuildCommand::Test(None)),
+            ["test", "--daemon", "--parallel", "--quiet"]
+        );
+    }
+
+    #[test]
+    fn run_mutations_should_all_pass() {
+        let dir = PathBuf::from(KOTLIN_CODE_DIR)
+            .join("mutations")
+            .read_dir()
+            .unwrap();
+        let file_backup = include_str!(
+            "../../tests/kotlin-test-projects/kotlin-project/src/main/kotlin/Calculator.kt"
+        );
+        for entry in dir {
+            let entry = entry.unwrap().path();
+            let mut mutation = Mutation::new(
+                0,
+                0,
+                "new_op".into(),
+                "old_op".into(),
+                0,
+                crate::mutation_tool::MutationOperators::ArithmeticReplacementOperator,
+                "file_name".into(),
+            );
+            run(
+                &Gradle::new(&PathBuf::from(KOTLIN_CODE_DIR).join("kotlin-project")),
+                &PathBuf::from(KOTLIN_CODE_DIR).join("kotlin-project"),
+                &entry,
+                &PathBuf::from(
+                    "./tests/kotlin-test-projects/kotlin-project/src/main/kotlin/Calculator.kt",
+                ),
+                None,
+                &mut mutation,
+            )
+            .unwrap();
+            // Reset File
+            fs::write(
+                PathBuf::from(
+                    "./tests/kotlin-test-projects/kotlin-project/src/main/kotlin/Calculator.kt",
+                ),
+                file_backup,
+            )
+            .unwrap();
+            // Get File Name
+            let file_name = entry
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .strip_suffix(".kt")
+                .unwrap();
+            let expected = match file_name {
+                "BuildFails" => MutationResult::BuildFailed,
+                "Killed" => MutationResult::Killed,
+                "Survived" => MutationResult::Survived,
+                _ => unreachable!(),
+            };
+            assert_eq!(expected, mutation.result, "Failed for: {}", file_name)
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::{
+    build_system::{script_command, BuildCommand, BuildSystem},
+    error::{MutantKrakenError, Result},
+};
+
+/// Builds projects with Maven. The Maven wrapper is used when the project has one,
+/// otherwise `mvn` has to be on the `PATH`
+pub struct Maven;
+
+impl BuildSystem for Maven {
+    fn name(&self) -> &'static str {
+        "maven"
+    }
+
+    fn validate(&self, project_path: &Path) -> Result<()> {
+        if !project_path.join("pom.xml").exists() {
+            return Err(MutantKrakenError::Error(
+                "pom.xml does not exist at the root of this project".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Surefire fails when none of the tests match the `-Dtest` filter
+    fn no_tests_matched(&self, output: &str) -> bool {
+        output.contains("No tests matching pattern") || output.contains("No tests were executed")
+    }
+
+    fn command(&self, project_path: &Path, command: &BuildCommand) -> Command {
+        let wrapper = if cfg!(windows) { "mvnw.cmd" } else { "mvnw" };
+        let mut cmd = script_command(
+            Some(wrapper).filter(|wrapper| project_path.join(wrapper).exists()),
+            "mvn",
+        );
+        cmd.args(["--batch-mode", "--quiet"]);
+        match command {
+            BuildCommand::Assemble(_) => {
+                cmd.arg("compile");
+            }
+            BuildCommand::Clean => {
+                cmd.arg("clean");
+            }
+            // The tests are not filtered by file, since the tests of a file are not always named after it
+            BuildCommand::Test(_) => {
+                cmd.arg("test");
+            }
+            BuildCommand::TestMethods(_, tests) => {
+                // Surefire selects a method with `Class#method`
+                let tests = tests
+                    .iter()
+                    .map(|test| match test.rsplit_once('.') {
+                        Some((class, method)) => format!("{}#{}", class, method),
+                        None => test.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                cmd.args(["test".to_string(), format!("-Dtest={}", tests.join(","))]);
+            }
+        }
+        cmd
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::build_system::test_util::TempDir;
+    use std::fs;
+
+    fn args(project_path: &Path, command: BuildCommand) -> Vec<String> {
+        Maven
+            .command(project_path, &command)
+            .get_args()
+            .map(|a| a.to_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_maven_commands() {
+        let project_path = Path::new("./tests/kotlin-test-projects/kotlin-project");
+        assert_eq!(
+            Maven
+                .command(project_path, &BuildCommand::Clean)
+                .get_program(),
+            "mvn"
+        );
+        assert_eq!(
+            args(project_path, BuildCommand::Assemble(None)),
+            ["--batch-mode", "--quiet", "compile"]
+        );
+        assert_eq!(
+            args(project_path, BuildCommand::Clean),
+            ["--batch-mode", "--quiet", "clean"]
+        );
+        assert_eq!(
+            args(project_path, BuildCommand::Test(None)),
+            ["--batch-mode", "--quiet", "test"]
+        );
+        assert_eq!(
+            args(
+                project_path,
+                BuildCommand::Test(Some(Path::new("src/main/kotlin/Calculator.kt")))
+            ),
+            ["--batch-mode", "--quiet", "test"]
+        );
+        assert_eq!(
+            args(
+                project_path,
+                BuildCommand::TestMethods(
+                    Some(Path::new("src/main/kotlin/Calculator.kt")),
+                    &[
+                        "com.example.CalculatorTest.testAdd".into(),
+                        "com.example.MainTest.testRun".into()
+                    ]
+                )
+            ),
+            [
+                "--batch-mode",
+                "--quiet",
+                "test",
+                "-Dtest=com.example.CalculatorTest#testAdd,com.example.MainTest#testRun"
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_maven_uses_wrapper() {
+        let project_path = TempDir::new("maven_wrapper_project");
+        fs::write(project_path.join("mvnw"), "").unwrap();
+        assert_eq!(
+            Maven
+                .command(&project_path, &BuildCommand::Assemble(None))
+                .get_program(),
+            "./mvnw"
+        );
+    }
+
+    #[test]
+    fn test_maven_requires_pom() {
+        let project_path = Path::new("./tests/kotlin-test-projects/kotlin-project");
+        assert_eq!(
+            Maven.validate(project_path),
+            Err(MutantKrakenError::Error(
+                "pom.xml does not exist at the root of this project".into()
+            ))
+        );
+    }
+}
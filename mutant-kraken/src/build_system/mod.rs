@@ -0,0 +1,516 @@
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    time::Duration,
+};
+use wait_timeout::ChildExt;
+
+use crate::{
+    config::MutantKrakenConfig,
+    error::{MutantKrakenError, Result},
+    mutation_tool::{Mutation, MutationResult},
+};
+
+mod custom;
+mod gradle;
+mod maven;
+
+pub use custom::CustomBuild;
+pub use gradle::Gradle;
+pub use maven::Maven;
+
+/// A command run by a build system. The file is the mutated source file, relative to the
+/// root of the project
+#[derive(PartialEq, Eq)]
+pub enum BuildCommand<'a> {
+    /// Compiles the project, or the part of it the file is in
+    Assemble(Option<&'a Path>),
+    Clean,
+    /// Runs the tests of the file, or every test when there is no file
+    Test(Option<&'a Path>),
+    /// Runs the given test methods, like `com.example.CalculatorTest.testAdd`, for the file
+    TestMethods(Option<&'a Path>, &'a [String]),
+}
+
+/// The build systems that can build and test a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum BuildSystemKind {
+    Gradle,
+    Maven,
+}
+
+impl BuildSystemKind {
+    /// Detects the build system of the project from its build files.
+    /// Projects without a `pom.xml` are built with Gradle
+    pub fn detect(project_path: &Path) -> Self {
+        let has_gradle_build = [
+            "build.gradle",
+            "build.gradle.kts",
+            "settings.gradle",
+            "settings.gradle.kts",
+        ]
+        .iter()
+        .any(|file| project_path.join(file).exists());
+        if !has_gradle_build && project_path.join("pom.xml").exists() {
+            BuildSystemKind::Maven
+        } else {
+            BuildSystemKind::Gradle
+        }
+    }
+
+    /// Gets the build system of the project. The build system set in the config is used
+    /// when there is one, otherwise it is detected from the project
+    pub fn from_config(config: Option<BuildSystemKind>, project_path: &Path) -> Self {
+        config.unwrap_or_else(|| Self::detect(project_path))
+    }
+
+    pub fn build_system(&self, project_path: &Path) -> Box<dyn BuildSystem> {
+        match self {
+            BuildSystemKind::Gradle => Box::new(Gradle::new(project_path)),
+            BuildSystemKind::Maven => Box::new(Maven),
+        }
+    }
+}
+
+/// Gets the build system of the project. The commands of the `build` section of the config are
+/// used when it is set, otherwise the project is built with Gradle or Maven
+pub fn from_config(config: &MutantKrakenConfig, project_path: &Path) -> Box<dyn BuildSystem> {
+    match &config.build {
+        Some(build) => Box::new(CustomBuild::new(build.clone())),
+        None => BuildSystemKind::from_config(config.general.build_system, project_path)
+            .build_system(project_path),
+    }
+}
+
+/// A build system that can assemble a project and run its tests
+pub trait BuildSystem: Send + Sync {
+    /// The name of the build system, used in errors and logs
+    fn name(&self) -> &'static str;
+
+    /// Checks if the project can be built with this build system
+    fn validate(&self, project_path: &Path) -> Result<()>;
+
+    /// Gets the directory the commands are run in
+    fn working_dir(&self, project_path: &Path) -> PathBuf {
+        project_path.to_path_buf()
+    }
+
+    /// Gets the module of the project the file is in, for build systems that split projects
+    /// into modules. The file is relative to the root of the project
+    fn module_of(&self, _file: &Path) -> Option<String> {
+        None
+    }
+
+    /// Creates the command that runs `command` for the project.
+    /// The command is run from the working directory
+    fn command(&self, project_path: &Path, command: &BuildCommand) -> Command;
+
+    /// Starts compiling the project, or the part of it the file is in
+    fn assemble(&self, project_path: &Path, file: Option<&Path>) -> Result<Child> {
+        self.spawn(project_path, &BuildCommand::Assemble(file))
+    }
+
+    /// Starts running the tests of the file, or every test when there is no file
+    fn test(&self, project_path: &Path, file: Option<&Path>) -> Result<Child> {
+        self.spawn(project_path, &BuildCommand::Test(file))
+    }
+
+    /// Starts running the given test methods for the file
+    fn test_methods(
+        &self,
+        project_path: &Path,
+        file: Option<&Path>,
+        tests: &[String],
+    ) -> Result<Child> {
+        self.spawn(project_path, &BuildCommand::TestMethods(file, tests))
+    }
+
+    /// Checks if the output of a failed test run says that none of the selected test methods
+    /// were found, rather than that a test failed
+    fn no_tests_matched(&self, _output: &str) -> bool {
+        false
+    }
+
+    /// Starts removing the build output of the project
+    fn clean(&self, project_path: &Path) -> Result<Child> {
+        self.spawn(project_path, &BuildCommand::Clean)
+    }
+
+    /// Prepares the copy of the project a worker builds its mutants in. It runs once for each
+    /// worker, before its first mutant, so the mutants after it are built incrementally
+    fn prepare_worker(&self, project_path: &Path) -> Result<()> {
+        self.validate(project_path)?;
+        if !self.wait(self.clean(project_path)?)? {
+            tracing::warn!("Failed to clean {}", project_path.display());
+        }
+        Ok(())
+    }
+
+    /// Cleans up after a worker, once every worker has built its mutants and before the copy
+    /// of the project it built them in is removed
+    fn finish_worker(&self, _project_path: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    /// Checks that the project builds and that all of its tests pass before it is mutated
+    fn baseline_check(&self, project_path: &Path) -> Result<()> {
+        self.validate(project_path)?;
+        if !self.wait(self.assemble(project_path, None)?)? {
+            return Err(MutantKrakenError::Error(
+                "Project does not build successfully. Please fix the errors and try again.".into(),
+            ));
+        }
+        if !self.wait(self.test(project_path, None)?)? {
+            return Err(MutantKrakenError::Error(
+                "Project tests do not pass. Please fix the errors and try again.".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Starts the command in the working directory
+    fn spawn(&self, project_path: &Path, command: &BuildCommand) -> Result<Child> {
+        self.command(project_path, command)
+            .current_dir(self.working_dir(project_path))
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| self.error(e))
+    }
+
+    /// Waits for the command to finish and checks if it succeeded
+    fn wait(&self, child: Child) -> Result<bool> {
+        let output = child.wait_with_output().map_err(|e| self.error(e))?;
+        Ok(output.status.success())
+    }
+
+    fn error(&self, error: std::io::Error) -> MutantKrakenError {
+        MutantKrakenError::Error(format!("Failed to run {} command: {}", self.name(), error))
+    }
+}
+
+/// Builds the project with the mutated file and runs its tests
+///
+/// # Arguments
+///
+/// * `build_system` - The build system of the project.
+/// * `config_path` - The root of the project.
+/// * `mutated_file_path` - The mutated file.
+/// * `original_file_path` - The file in the project the mutated file replaces.
+/// * `covering_tests` - The tests that cover the mutated line. The tests of the file are run
+///   when the coverage of the line is not known, or when none of the tests are found.
+/// * `mutation` - The mutation, which gets the result of the build and tests.
+pub fn run(
+    build_system: &dyn BuildSystem,
+    config_path: &Path,
+    mutated_file_path: &Path,
+    original_file_path: &Path,
+    covering_tests: Option<&[String]>,
+    mutation: &mut Mutation,
+) -> Result<()> {
+    build_system.validate(config_path)?;
+
+    // Copy the mutated file to the original file
+    fs::copy(mutated_file_path, original_file_path)?;
+    let file = original_file_path
+        .strip_prefix(config_path)
+        .unwrap_or(original_file_path);
+    test_filter(file).ok_or(MutantKrakenError::ConversionError)?;
+
+    // Compile the project first, skip if compilation fails
+    let res = build_system
+        .assemble(config_path, Some(file))?
+        .wait_with_output()
+        .map_err(|e| build_system.error(e))?;
+
+    if !res.status.success() {
+        tracing::info!("Build failed for: {}", mutated_file_path.display());
+        mutation.result = MutationResult::BuildFailed;
+        // Log the error
+        tracing::info!("Error: {}", String::from_utf8_lossy(&res.stderr));
+        return Ok(());
+    }
+
+    let child_process = match covering_tests {
+        // A mutant that no test covers can not be killed
+        Some([]) => {
+            tracing::info!(
+                "No test covers the mutant for: {}",
+                mutated_file_path.display()
+            );
+            mutation.result = MutationResult::Survived;
+            return Ok(());
+        }
+        Some(tests) => build_system.test_methods(config_path, Some(file), tests)?,
+        None => build_system.test(config_path, Some(file))?,
+    };
+    tracing::debug!("Running test for mutation: {}", mutated_file_path.display());
+    let (mut result, output) = wait_for_tests(child_process, mutated_file_path)?;
+    // The reports can name tests the build system does not find, like tests that were renamed
+    // since the reports were written
+    if covering_tests.is_some()
+        && result == MutationResult::Killed
+        && build_system.no_tests_matched(&output)
+    {
+        tracing::info!(
+            "None of the covering tests were found, running the tests of the file for: {}",
+            mutated_file_path.display()
+        );
+        let child_process = build_system.test(config_path, Some(file))?;
+        result = wait_for_tests(child_process, mutated_file_path)?.0;
+    }
+    mutation.result = result;
+    Ok(())
+}
+
+/// Waits for the tests of a mutant to finish, and gets the result of the mutant with the output
+/// of the tests
+fn wait_for_tests(
+    mut child_process: Child,
+    mutated_file_path: &Path,
+) -> Result<(MutationResult, String)> {
+    // The output is read while the tests run, so they never wait on a full pipe
+    let read = |pipe: Option<Box<dyn Read + Send>>| {
+        pipe.map(|mut pipe| {
+            std::thread::spawn(move || {
+                let mut output = String::new();
+                let _ = pipe.read_to_string(&mut output);
+                output
+            })
+        })
+    };
+    let stdout = read(child_process.stdout.take().map(|p| Box::new(p) as _));
+    let stderr = read(child_process.stderr.take().map(|p| Box::new(p) as _));
+    // Will need to keep an eye on this timeout. The reason its here is because of infinite loops that
+    // can occur from the mutations.
+    let status = match child_process.wait_timeout(Duration::from_secs(120)) {
+        Ok(Some(status)) => status,
+        Ok(None) => {
+            child_process.kill().map_err(|e| {
+                MutantKrakenError::Error(format!("Failed to kill child process: {}", e))
+            })?;
+            tracing::error!("Test timed out for: {}", mutated_file_path.display());
+            return Ok((MutationResult::Timeout, String::new()));
+        }
+        Err(e) => {
+            tracing::error!("Test failed: {}", e);
+            child_process.kill().map_err(|e| {
+                MutantKrakenError::Error(format!("Failed to kill child process: {}", e))
+            })?;
+            return Ok((MutationResult::Failed, String::new()));
+        }
+    };
+    let output = [stdout, stderr]
+        .into_iter()
+        .flatten()
+        .filter_map(|reader| reader.join().ok())
+        .collect();
+    if status.success() {
+        tracing::info!("Mutant survived for file: {}", mutated_file_path.display());
+        Ok((MutationResult::Survived, output))
+    } else {
+        tracing::info!("Mutant killed for file: {}", mutated_file_path.display());
+        Ok((MutationResult::Killed, output))
+    }
+}
+
+/// Gets the name of a Kotlin file without `.kt`, which the tests of the file are named after
+fn test_filter(file: &Path) -> Option<&str> {
+    file.file_name()?.to_str()?.strip_suffix(".kt")
+}
+
+/// Creates a command that runs a script at the root of the project, or a program on the
+/// `PATH` when `script` is `None`
+fn script_command(script: Option<&str>, program: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", script.unwrap_or(program)]);
+        cmd
+    } else if cfg!(unix) {
+        match script {
+            Some(script) => Command::new(format!("./{}", script)),
+            None => Command::new(program),
+        }
+    } else {
+        panic!("Unsupported OS");
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use std::{
+        fs,
+        ops::Deref,
+        path::{Path, PathBuf},
+    };
+
+    /// A directory of its own in the temp dir, which is removed when it is dropped
+    pub struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(name)
+                .join(uuid::Uuid::new_v4().to_string());
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Deref for TempDir {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::TempDir;
+
+    fn project_with(files: &[&str]) -> TempDir {
+        let dir = TempDir::new("build_system_detect");
+        for file in files {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_detect_build_system() {
+        assert_eq!(
+            BuildSystemKind::detect(&project_with(&["pom.xml"])),
+            BuildSystemKind::Maven
+        );
+        assert_eq!(
+            BuildSystemKind::detect(&project_with(&["build.gradle.kts", "gradlew"])),
+            BuildSystemKind::Gradle
+        );
+        assert_eq!(
+            BuildSystemKind::detect(&project_with(&["build.gradle"])),
+            BuildSystemKind::Gradle
+        );
+        // Gradle wins when a project has both
+        assert_eq!(
+            BuildSystemKind::detect(&project_with(&["pom.xml", "settings.gradle.kts"])),
+            BuildSystemKind::Gradle
+        );
+        assert_eq!(
+            BuildSystemKind::detect(&project_with(&[])),
+            BuildSystemKind::Gradle
+        );
+    }
+
+    #[test]
+    fn test_config_overrides_detected_build_system() {
+        let project = project_with(&["build.gradle.kts"]);
+        assert_eq!(
+            BuildSystemKind::from_config(Some(BuildSystemKind::Maven), &project),
+            BuildSystemKind::Maven
+        );
+        assert_eq!(
+            BuildSystemKind::from_config(None, &project),
+            BuildSystemKind::Gradle
+        );
+        assert_eq!(
+            BuildSystemKind::Maven.build_system(&project).name(),
+            "maven"
+        );
+        assert_eq!(
+            BuildSystemKind::Gradle.build_system(&project).name(),
+            "gradle"
+        );
+    }
+
+    /// A build system whose selected test methods fail with the given output, and whose
+    /// other tests pass
+    #[cfg(unix)]
+    struct FailingTestMethods(&'static str);
+
+    #[cfg(unix)]
+    impl BuildSystem for FailingTestMethods {
+        fn name(&self) -> &'static str {
+            "failing test methods"
+        }
+
+        fn validate(&self, _project_path: &Path) -> Result<()> {
+            Ok(())
+        }
+
+        fn no_tests_matched(&self, output: &str) -> bool {
+            output.contains("No tests found")
+        }
+
+        fn command(&self, _project_path: &Path, command: &BuildCommand) -> Command {
+            let mut cmd = Command::new("sh");
+            match command {
+                BuildCommand::TestMethods(..) => {
+                    cmd.args(["-c", &format!("echo '{}' >&2; exit 1", self.0)])
+                }
+                _ => cmd.args(["-c", "true"]),
+            };
+            cmd
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_falls_back_to_tests_of_file_when_no_test_matches() {
+        let project = project_with(&["Calculator.kt", "Mutated.kt"]);
+        let result = |build_system: &dyn BuildSystem| {
+            let mut mutation = Mutation::new(
+                0,
+                0,
+                "-".into(),
+                "+".into(),
+                0,
+                crate::mutation_tool::MutationOperators::ArithmeticReplacementOperator,
+                "Calculator.kt".into(),
+            );
+            run(
+                build_system,
+                &project,
+                &project.join("Mutated.kt"),
+                &project.join("Calculator.kt"),
+                Some(&["com.example.CalculatorTest.testAdd".into()]),
+                &mut mutation,
+            )
+            .unwrap();
+            mutation.result
+        };
+        // The tests of the file pass, so the mutant survives
+        assert_eq!(
+            result(&FailingTestMethods("No tests found for given includes")),
+            MutationResult::Survived
+        );
+        assert_eq!(
+            result(&FailingTestMethods("There were failing tests")),
+            MutationResult::Killed
+        );
+    }
+
+    #[test]
+    fn test_build_section_overrides_build_system() {
+        let project = project_with(&["pom.xml"]);
+        let mut config = MutantKrakenConfig::default();
+        assert_eq!(from_config(&config, &project).name(), "maven");
+        config.build = Some(crate::config::BuildConfig {
+            build_command: "kotlinc Main.kt".into(),
+            test_command: "kotlin MainKt".into(),
+            clean_command: None,
+            working_dir: None,
+        });
+        assert_eq!(from_config(&config, &project).name(), "custom build");
+    }
+}
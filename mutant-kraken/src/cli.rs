@@ -44,6 +44,11 @@ pub struct MutationCommandConfig {
     /// Error will be thrown if the path is not a directory
     #[clap(default_value = ".")]
     pub path: String,
+
+    /// The seed used for the random choices made while mutating
+    /// Overrides the seed in the config file
+    #[clap(long)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Args, Debug, Clone)]
@@ -60,6 +65,7 @@ impl Default for MutationCommandConfig {
                 .expect("Could not get the current working directory")
                 .display()
                 .to_string(),
+            seed: None,
         }
     }
 }
@@ -93,19 +99,22 @@ pub fn run_cli() {
 
     match args.command {
         Commands::Mutate(mutate_config) => {
-            let config = MutantKrakenConfig::load_config(mutate_config.path.clone());
+            let mut config = MutantKrakenConfig::load_config(mutate_config.path.clone());
+            if mutate_config.seed.is_some() {
+                config.general.seed = mutate_config.seed;
+            }
             _guard = setup_logging(&config.logging.log_level, mutate_config.path.clone());
-            let mut tool = mutate_tool_builder
+            let res = mutate_tool_builder
                 .set_mutate_config(mutate_config)
                 .set_general_config(config)
                 .set_mutation_comment(true)
-                .build();
-            let res = match tool.mutantkraken_config.general.timeout {
-                Some(timeout) => {
-                    run_with_timeout(move || tool.mutate(), Duration::from_secs(timeout))
-                }
-                None => tool.mutate(),
-            };
+                .build()
+                .and_then(|mut tool| match tool.mutantkraken_config.general.timeout {
+                    Some(timeout) => {
+                        run_with_timeout(move || tool.mutate(), Duration::from_secs(timeout))
+                    }
+                    None => tool.mutate(),
+                });
             if let Err(e) = res {
                 let error_msg = match e {
                     error::MutantKrakenError::FileReadingError(msg) => msg,
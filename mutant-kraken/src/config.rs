@@ -1,8 +1,9 @@
-use std::{fs, io::BufReader, path::Path};
+use std::{collections::BTreeMap, fs, io::BufReader, path::Path};
 
 use serde::{Deserialize, Serialize};
 
-use crate::mutation_tool::MutationOperators;
+use crate::build_system::BuildSystemKind;
+use crate::mutation_tool::{Cardinality, LiteralVariant, MutationOperators, WhenBranchMode};
 
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, Eq, Clone)]
 pub struct MutantKrakenConfig {
@@ -11,6 +12,10 @@ pub struct MutantKrakenConfig {
     pub threading: ThreadingConfig,
     pub output: OutputConfig,
     pub logging: LoggingConfig,
+    /// The commands used to build and test the project, for projects that are not
+    /// built with Gradle or Maven
+    #[serde(default)]
+    pub build: Option<BuildConfig>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -21,6 +26,89 @@ pub struct GeneralConfig {
 
     #[serde(default)]
     pub operators: Vec<MutationOperators>,
+
+    /// The values each literal operator changes a literal to.
+    /// Operators that are not listed use every variant
+    #[serde(default = "default_literal_variants")]
+    pub literal_variants: BTreeMap<MutationOperators, Vec<LiteralVariant>>,
+
+    /// The seed used for every random choice made by the mutation operators.
+    /// A random seed is picked when it is not set
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// The mutants the WhenRemoveBranchOperator creates for each when
+    #[serde(default)]
+    pub when_branch_mode: WhenBranchMode,
+
+    /// The number of mutants each operator keeps for a node.
+    /// Operators that are not listed use their default cardinality
+    #[serde(default)]
+    pub cardinality: BTreeMap<MutationOperators, Cardinality>,
+
+    /// Operators defined by the user with tree-sitter query files
+    #[serde(default)]
+    pub custom_operators: Vec<CustomOperatorConfig>,
+
+    /// The build system used to build and test the project.
+    /// It is detected from the build files of the project when it is not set
+    #[serde(default)]
+    pub build_system: Option<BuildSystemKind>,
+
+    /// The directory, relative to the project, of the per-test JaCoCo or Kover XML reports
+    /// written while the tests run. Each mutant then only runs the tests that cover its line
+    #[serde(default)]
+    pub coverage_reports: Option<String>,
+
+    /// Treats the mutants made inside `override fun toString()` as equivalent. Off by default,
+    /// as tests that check the text of an object kill them
+    #[serde(default)]
+    pub to_string_equivalent: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct CustomOperatorConfig {
+    /// The name the mutations are reported under
+    pub name: String,
+    /// The path to the `.scm` query file, relative to the project
+    pub query_file: String,
+    /// The text the captured node is replaced with. `{capture}` is replaced
+    /// with the text of the node captured as `@capture`
+    pub replacement: String,
+    /// The capture that is replaced. The first capture of the match is used when it is not set
+    #[serde(default)]
+    pub capture: Option<String>,
+}
+
+impl GeneralConfig {
+    /// Gets the literal variants the given operator should use
+    pub fn get_literal_variants(&self, operator: &MutationOperators) -> Vec<LiteralVariant> {
+        self.literal_variants
+            .get(operator)
+            .cloned()
+            .unwrap_or_else(LiteralVariant::all)
+    }
+
+    /// Gets the cardinality the given operator should use
+    pub fn get_cardinality(&self, operator: &MutationOperators) -> Cardinality {
+        self.cardinality
+            .get(operator)
+            .copied()
+            .unwrap_or_else(|| operator.get_default_cardinality())
+    }
+}
+
+fn default_literal_variants() -> BTreeMap<MutationOperators, Vec<LiteralVariant>> {
+    BTreeMap::from([
+        (
+            MutationOperators::ElvisLiteralChangeOperator,
+            LiteralVariant::all(),
+        ),
+        (
+            MutationOperators::LiteralChangeOperator,
+            LiteralVariant::all(),
+        ),
+    ])
 }
 
 impl Default for GeneralConfig {
@@ -45,11 +133,49 @@ impl Default for GeneralConfig {
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
                 FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator,
             ],
+            literal_variants: default_literal_variants(),
+            seed: None,
+            when_branch_mode: WhenBranchMode::Random,
+            cardinality: BTreeMap::new(),
+            custom_operators: vec![],
+            build_system: None,
+            coverage_reports: None,
+            to_string_equivalent: false,
         }
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct BuildConfig {
+    /// The command that compiles the project
+    pub build_command: String,
+    /// The command that runs the tests. `{test_filter}` is replaced with the quoted name of the
+    /// mutated file without `.kt`, or with an empty argument when every test is run
+    pub test_command: String,
+    /// The command that removes the build output. It runs once for each worker, before its first mutant
+    #[serde(default)]
+    pub clean_command: Option<String>,
+    /// The directory the commands are run in, relative to the project
+    #[serde(default)]
+    pub working_dir: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct LoggingConfig {
     pub log_level: String,
@@ -147,6 +273,24 @@ mod tests {
     fn test_default_general_config() {
         let default_general = GeneralConfig::default();
         assert_eq!(default_general.timeout, None);
+        assert_eq!(default_general.seed, None);
+        assert!(default_general.custom_operators.is_empty());
+        assert_eq!(default_general.build_system, None);
+        assert_eq!(default_general.coverage_reports, None);
+        assert!(!default_general.to_string_equivalent);
+        assert_eq!(default_general.when_branch_mode, WhenBranchMode::Random);
+        assert_eq!(
+            default_general.get_cardinality(&ExceptionChangeOperator),
+            Cardinality::Sampled(1)
+        );
+        assert_eq!(
+            default_general.get_cardinality(&FunctionalReplacementOperator),
+            Cardinality::Sampled(1)
+        );
+        assert_eq!(
+            default_general.get_cardinality(&ArithmeticReplacementOperator),
+            Cardinality::Exhaustive
+        );
         assert_eq!(
             default_general.operators,
             vec![
@@ -165,10 +309,38 @@ mod tests {
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
                 FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator,
             ]
         );
     }
 
+    #[test]
+    fn test_default_literal_variants() {
+        let default_general = GeneralConfig::default();
+        assert_eq!(
+            default_general.get_literal_variants(&LiteralChangeOperator),
+            LiteralVariant::all()
+        );
+        assert_eq!(
+            default_general.get_literal_variants(&ElvisLiteralChangeOperator),
+            LiteralVariant::all()
+        );
+    }
+
     #[test]
     fn test_default_ignore_config() {
         let default_ignore = IgnoreConfig::default();
@@ -191,7 +363,7 @@ mod tests {
     #[test]
     fn test_default_output_config() {
         let default_output = OutputConfig::default();
-        assert_eq!(default_output.display_end_table, false);
+        assert!(!default_output.display_end_table);
     }
 
     #[test]
@@ -216,13 +388,29 @@ mod tests {
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
                 FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator,
             ]
         );
         assert_eq!(config.ignore.ignore_files.len(), 1);
         assert_eq!(config.ignore.ignore_directories.len(), 6);
         assert_eq!(config.threading.max_threads, 30);
-        assert_eq!(config.output.display_end_table, false);
+        assert!(!config.output.display_end_table);
         assert_eq!(config.logging.log_level, "info");
+        assert_eq!(config.build, None);
     }
 
     #[test]
@@ -239,6 +427,31 @@ mod tests {
                     MutationOperators::UnaryRemovalOperator,
                     MutationOperators::AssignmentReplacementOperator,
                 ],
+                literal_variants: BTreeMap::from([(
+                    MutationOperators::LiteralChangeOperator,
+                    vec![LiteralVariant::Zero, LiteralVariant::EmptyString],
+                )]),
+                seed: Some(42),
+                when_branch_mode: WhenBranchMode::AllBranches,
+                cardinality: BTreeMap::from([
+                    (
+                        MutationOperators::ExceptionChangeOperator,
+                        Cardinality::Exhaustive,
+                    ),
+                    (
+                        MutationOperators::FunctionalReplacementOperator,
+                        Cardinality::Sampled(2),
+                    ),
+                ]),
+                custom_operators: vec![CustomOperatorConfig {
+                    name: "RequireNotNullOperator".into(),
+                    query_file: "queries/require_not_null.scm".into(),
+                    replacement: "checkNotNull".into(),
+                    capture: Some("fn".into()),
+                }],
+                build_system: Some(BuildSystemKind::Maven),
+                coverage_reports: Some("build/reports/coverage".into()),
+                to_string_equivalent: true,
             },
             ignore: IgnoreConfig {
                 ignore_files: vec!["file1".into(), "file2".into()],
@@ -251,6 +464,12 @@ mod tests {
             logging: LoggingConfig {
                 log_level: "debug".into(),
             },
+            build: Some(BuildConfig {
+                build_command: "kotlinc src -d out".into(),
+                test_command: "./run-tests.sh {test_filter}".into(),
+                clean_command: None,
+                working_dir: Some("app".into()),
+            }),
         };
 
         let config_json = serde_json::to_string_pretty(&config).unwrap();
@@ -266,11 +485,61 @@ mod tests {
                 MutationOperators::AssignmentReplacementOperator
             ]
         );
+        assert_eq!(
+            config
+                .general
+                .get_literal_variants(&MutationOperators::LiteralChangeOperator),
+            vec![LiteralVariant::Zero, LiteralVariant::EmptyString]
+        );
+        assert_eq!(
+            config
+                .general
+                .get_literal_variants(&MutationOperators::ElvisLiteralChangeOperator),
+            LiteralVariant::all()
+        );
+        assert_eq!(config.general.seed, Some(42));
+        assert_eq!(config.general.when_branch_mode, WhenBranchMode::AllBranches);
+        assert_eq!(
+            config
+                .general
+                .get_cardinality(&MutationOperators::ExceptionChangeOperator),
+            Cardinality::Exhaustive
+        );
+        assert_eq!(
+            config
+                .general
+                .get_cardinality(&MutationOperators::FunctionalReplacementOperator),
+            Cardinality::Sampled(2)
+        );
+        assert_eq!(
+            config.general.custom_operators,
+            vec![CustomOperatorConfig {
+                name: "RequireNotNullOperator".into(),
+                query_file: "queries/require_not_null.scm".into(),
+                replacement: "checkNotNull".into(),
+                capture: Some("fn".into()),
+            }]
+        );
+        assert_eq!(config.general.build_system, Some(BuildSystemKind::Maven));
+        assert_eq!(
+            config.general.coverage_reports,
+            Some("build/reports/coverage".into())
+        );
+        assert!(config.general.to_string_equivalent);
         assert_eq!(config.ignore.ignore_files, vec!["file1", "file2"]);
         assert_eq!(config.ignore.ignore_directories, vec!["dir1", "dir2"]);
         assert_eq!(config.threading.max_threads, 42);
-        assert_eq!(config.output.display_end_table, true);
+        assert!(config.output.display_end_table);
         assert_eq!(config.logging.log_level, "debug");
+        assert_eq!(
+            config.build,
+            Some(BuildConfig {
+                build_command: "kotlinc src -d out".into(),
+                test_command: "./run-tests.sh {test_filter}".into(),
+                clean_command: None,
+                working_dir: Some("app".into()),
+            })
+        );
     }
 
     #[test]
@@ -313,6 +582,21 @@ mod tests {
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
                 FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator,
             ]
         );
     }
@@ -345,6 +629,21 @@ mod tests {
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
                 FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator,
             ]
         );
     }
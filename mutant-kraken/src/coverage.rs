@@ -0,0 +1,330 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::error::{MutantKrakenError, Result};
+
+/// The tests that cover each line of the source files of a project.
+///
+/// The map is read from per-test JaCoCo or Kover XML reports. A report only holds the lines
+/// covered by all of the tests that ran, so each report has to hold the coverage of a single
+/// test, and be named after it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageMap {
+    /// The lines of each source file, and the tests that cover them. The files are keyed by
+    /// their path in their package, like `com/example/Calculator.kt`
+    files: HashMap<String, HashMap<usize, Vec<String>>>,
+}
+
+impl CoverageMap {
+    /// Reads every `.xml` report in the directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `report_dir` - The directory of the reports. Each report holds the coverage of one test,
+    ///   and is named after it, like `com.example.CalculatorTest.testAdd.xml`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the map, or an error if a report could not be read or parsed.
+    pub fn load(report_dir: &Path) -> Result<Self> {
+        let mut coverage = Self::default();
+        let entries = fs::read_dir(report_dir).map_err(|e| {
+            MutantKrakenError::FileReadingError(format!("{}: {}", report_dir.display(), e))
+        })?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|extension| extension != "xml") {
+                continue;
+            }
+            let test = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or(MutantKrakenError::ConversionError)?;
+            // Reports that are not named after a test, like the report of a whole test task,
+            // would select tests that do not exist
+            if !is_test_name(test) {
+                tracing::warn!(
+                    "Skipping the coverage report {}, which is not named after a test",
+                    path.display()
+                );
+                continue;
+            }
+            let report = fs::read_to_string(&path).map_err(|e| {
+                MutantKrakenError::FileReadingError(format!("{}: {}", path.display(), e))
+            })?;
+            coverage.add_report(test, &report)?;
+        }
+        Ok(coverage)
+    }
+
+    /// Adds the lines the test covers in the report to the map
+    pub fn add_report(&mut self, test: &str, report: &str) -> Result<()> {
+        let options = roxmltree::ParsingOptions {
+            // JaCoCo reports declare their DTD
+            allow_dtd: true,
+            ..Default::default()
+        };
+        let document = roxmltree::Document::parse_with_options(report, options).map_err(|e| {
+            MutantKrakenError::Error(format!("Invalid coverage report for {}: {}", test, e))
+        })?;
+        for package in document.descendants().filter(|n| n.has_tag_name("package")) {
+            let package_name = package.attribute("name").unwrap_or_default();
+            for source_file in package.children().filter(|n| n.has_tag_name("sourcefile")) {
+                let file_name = source_file.attribute("name").unwrap_or_default();
+                let key = if package_name.is_empty() {
+                    file_name.to_string()
+                } else {
+                    format!("{}/{}", package_name, file_name)
+                };
+                let lines = self.files.entry(key).or_default();
+                for line in source_file.children().filter(|n| n.has_tag_name("line")) {
+                    let Some(number) = line.attribute("nr").and_then(|nr| nr.parse().ok()) else {
+                        continue;
+                    };
+                    // Lines that are not covered are still recorded, so they can be told apart
+                    // from lines the report knows nothing about
+                    let tests = lines.entry(number).or_default();
+                    let covered_instructions = line
+                        .attribute("ci")
+                        .and_then(|ci| ci.parse::<usize>().ok())
+                        .unwrap_or_default();
+                    if covered_instructions > 0 && !tests.iter().any(|t| t == test) {
+                        tests.push(test.to_string());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the tests that cover a line of a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The package of the source file, like `com.example`, which is empty for
+    ///   the default package. See `package_of`.
+    /// * `file` - The path of the source file, relative to the project.
+    /// * `line` - The line number.
+    ///
+    /// # Returns
+    ///
+    /// The tests that cover the line, which is empty when no test covers it. Returns `None` when
+    /// the reports have no coverage for the line, for example when the file was never compiled.
+    pub fn tests_for(&self, package: &str, file: &Path, line: usize) -> Option<Vec<String>> {
+        let file_name = file.file_name()?.to_str()?;
+        // The reports name the package of a file, which is not always the directory it is in
+        let key = if package.is_empty() {
+            file_name.to_string()
+        } else {
+            format!("{}/{}", package.replace('.', "/"), file_name)
+        };
+        self.files.get(&key)?.get(&line).cloned()
+    }
+}
+
+/// Gets the package of a Kotlin file from its `package` header, which is empty for the
+/// default package
+pub fn package_of(source: &str) -> String {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_kotlin::language())
+        .expect("Error loading Kotlin grammar");
+    let Some(tree) = parser.parse(source, None) else {
+        return String::new();
+    };
+    let root = tree.root_node();
+    let package = root
+        .children(&mut root.walk())
+        .find(|node| node.kind() == "package_header")
+        .and_then(|header| {
+            header
+                .children(&mut header.walk())
+                .find(|node| node.kind() == "identifier")
+        })
+        .and_then(|identifier| identifier.utf8_text(source.as_bytes()).ok())
+        .map(|package| package.split_whitespace().collect())
+        .unwrap_or_default();
+    package
+}
+
+/// Checks if the name of a report is a test method in its class, like
+/// `com.example.CalculatorTest.testAdd`. The class name has to start with an uppercase letter
+fn is_test_name(name: &str) -> bool {
+    let is_identifier = |part: &str| {
+        part.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && part
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+    };
+    let Some((class, method)) = name.rsplit_once('.') else {
+        return false;
+    };
+    class.split('.').all(is_identifier)
+        && class
+            .rsplit('.')
+            .next()
+            .is_some_and(|class| class.starts_with(char::is_uppercase))
+        // Methods named with backticks can have spaces, but not the wildcards of test filters
+        && !method.trim().is_empty()
+        && !method.contains('*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::build_system::test_util::TempDir;
+
+    const ADD_TEST_REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<!DOCTYPE report PUBLIC "-//JACOCO//DTD Report 1.1//EN" "report.dtd">
+<report name="kotlin-project">
+  <sessioninfo id="worker-1" start="1700000000000" dump="1700000001000"/>
+  <package name="com/example">
+    <class name="com/example/Calculator" sourcefilename="Calculator.kt">
+      <method name="add" desc="(II)I" line="4">
+        <counter type="INSTRUCTION" missed="0" covered="4"/>
+      </method>
+    </class>
+    <sourcefile name="Calculator.kt">
+      <line nr="4" mi="0" ci="4" mb="0" cb="0"/>
+      <line nr="8" mi="4" ci="0" mb="0" cb="0"/>
+      <counter type="LINE" missed="1" covered="1"/>
+    </sourcefile>
+  </package>
+  <package name="">
+    <sourcefile name="Main.kt">
+      <line nr="2" mi="0" ci="3" mb="0" cb="0"/>
+    </sourcefile>
+  </package>
+</report>
+"#;
+
+    const SUBTRACT_TEST_REPORT: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<report name="kotlin-project">
+  <package name="com/example">
+    <sourcefile name="Calculator.kt">
+      <line nr="4" mi="0" ci="4" mb="0" cb="0"/>
+      <line nr="8" mi="4" ci="0" mb="0" cb="0"/>
+    </sourcefile>
+  </package>
+</report>
+"#;
+
+    fn coverage_map() -> CoverageMap {
+        let mut coverage = CoverageMap::default();
+        coverage
+            .add_report("com.example.CalculatorTest.testAdd", ADD_TEST_REPORT)
+            .unwrap();
+        coverage
+            .add_report(
+                "com.example.CalculatorTest.testSubtract",
+                SUBTRACT_TEST_REPORT,
+            )
+            .unwrap();
+        coverage
+    }
+
+    #[test]
+    fn test_tests_for_covered_line() {
+        let coverage = coverage_map();
+        assert_eq!(
+            coverage.tests_for(
+                "com.example",
+                Path::new("src/main/kotlin/com/example/Calculator.kt"),
+                4
+            ),
+            Some(vec![
+                "com.example.CalculatorTest.testAdd".to_string(),
+                "com.example.CalculatorTest.testSubtract".to_string()
+            ])
+        );
+        assert_eq!(
+            coverage.tests_for("", Path::new("src/main/kotlin/Main.kt"), 2),
+            Some(vec!["com.example.CalculatorTest.testAdd".to_string()])
+        );
+        // The package is not always the directory of the file
+        assert_eq!(
+            coverage.tests_for("com.example", Path::new("src/main/kotlin/Calculator.kt"), 4),
+            Some(vec![
+                "com.example.CalculatorTest.testAdd".to_string(),
+                "com.example.CalculatorTest.testSubtract".to_string()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_tests_for_uncovered_line() {
+        let coverage = coverage_map();
+        let calculator = Path::new("src/main/kotlin/com/example/Calculator.kt");
+        // No test covers the line
+        assert_eq!(
+            coverage.tests_for("com.example", calculator, 8),
+            Some(vec![])
+        );
+        // The reports know nothing about the line
+        assert_eq!(coverage.tests_for("com.example", calculator, 5), None);
+        // The file is in another package
+        assert_eq!(
+            coverage.tests_for(
+                "com.other",
+                Path::new("src/main/kotlin/com/other/Calculator.kt"),
+                4
+            ),
+            None
+        );
+        // Only the files in the default package have the coverage of its files
+        assert_eq!(
+            coverage.tests_for(
+                "com.example",
+                Path::new("src/main/kotlin/com/example/Main.kt"),
+                2
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_package_of() {
+        assert_eq!(
+            package_of("// Header\npackage com.example.data\n\nfun main() {}\n"),
+            "com.example.data"
+        );
+        assert_eq!(package_of("fun main() {}\n"), "");
+    }
+
+    #[test]
+    fn test_is_test_name() {
+        assert!(is_test_name("com.example.CalculatorTest.testAdd"));
+        assert!(is_test_name("CalculatorTest.adds two numbers"));
+        assert!(is_test_name("com.example.CalculatorTest$Nested.testAdd"));
+        assert!(!is_test_name("jacocoTestReport"));
+        assert!(!is_test_name("com.example.testAdd"));
+        assert!(!is_test_name("com.example.CalculatorTest.*"));
+        assert!(!is_test_name("build reports.CalculatorTest.testAdd"));
+    }
+
+    #[test]
+    fn test_load_reads_reports_named_after_tests() {
+        let report_dir = TempDir::new("coverage_reports");
+        fs::write(
+            report_dir.join("com.example.CalculatorTest.testAdd.xml"),
+            ADD_TEST_REPORT,
+        )
+        .unwrap();
+        fs::write(report_dir.join("README.md"), "Not a report").unwrap();
+        // The report of the whole test task is not named after a test, so it is skipped
+        fs::write(
+            report_dir.join("jacocoTestReport.xml"),
+            SUBTRACT_TEST_REPORT,
+        )
+        .unwrap();
+
+        let coverage = CoverageMap::load(&report_dir).unwrap();
+        assert_eq!(
+            coverage.tests_for("com.example", Path::new("com/example/Calculator.kt"), 4),
+            Some(vec!["com.example.CalculatorTest.testAdd".to_string()])
+        );
+        assert!(CoverageMap::load(&report_dir.join("missing")).is_err());
+        assert!(CoverageMap::default()
+            .add_report("test", "<report>")
+            .is_err());
+    }
+}
@@ -44,6 +44,7 @@ impl From<io::Error> for MutantKrakenError {
 }
 
 #[cfg(test)]
+#[allow(clippy::unnecessary_literal_unwrap)]
 mod test {
     use super::*;
     use std::io;
@@ -100,14 +101,14 @@ mod test {
     #[test]
     fn test_result_conversion_ok() {
         let result: Result<i32> = Ok(42);
-        assert_eq!(result.is_ok(), true);
+        assert!(result.is_ok());
         assert_eq!(result.unwrap(), 42);
     }
 
     #[test]
     fn test_result_conversion_err() {
         let result: Result<i32> = Err(MutantKrakenError::Error("Something went wrong".to_string()));
-        assert_eq!(result.is_err(), true);
+        assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
             MutantKrakenError::Error("Something went wrong".to_string())
@@ -1,10 +1,15 @@
-use std::{collections::HashMap, fs::File, io::Write, path::Path};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::File,
+    io::Write,
+    path::Path,
+};
 
 use horrorshow::{helper::doctype, html};
 
-use crate::mutation_tool::{Mutation, MutationResult};
+use crate::mutation_tool::{mutation_score, Mutation, MutationResult};
 
-pub fn build_html_page(data: &Vec<Mutation>, path: &Path) {
+pub fn build_html_page(data: &Vec<Mutation>, path: &Path, seed: u64) {
     // Group the mutations by file name
     let mut file_mutations = HashMap::new();
     for mutation in data {
@@ -12,6 +17,22 @@ pub fn build_html_page(data: &Vec<Mutation>, path: &Path) {
         let file_mutations = file_mutations.entry(file_name).or_insert(Vec::new());
         file_mutations.push(mutation);
     }
+    // Group the mutations by mutation type, so custom operators are listed under their own name
+    let mut type_mutations = BTreeMap::new();
+    for mutation in data {
+        let type_mutations = type_mutations
+            .entry(mutation.mutation_type.to_string())
+            .or_insert(Vec::new());
+        type_mutations.push(mutation);
+    }
+    // Group the mutations by module, for projects with modules
+    let mut module_mutations = BTreeMap::new();
+    for mutation in data {
+        if let Some(module) = &mutation.module {
+            let module_mutations = module_mutations.entry(module).or_insert(Vec::new());
+            module_mutations.push(mutation);
+        }
+    }
     let report = format!(
         "{}",
         html! {
@@ -36,7 +57,7 @@ pub fn build_html_page(data: &Vec<Mutation>, path: &Path) {
                     table(class="tg") {
                         thead {
                             tr {
-                                th(class="tg-baqh", colspan="5") {
+                                th(class="tg-baqh", colspan="6") {
                                     : "Mutant Kraken Results";
                                 }
                             }
@@ -55,6 +76,9 @@ pub fn build_html_page(data: &Vec<Mutation>, path: &Path) {
                                 td(class="tg-0lax") {
                                     : "# Killed";
                                 }
+                                td(class="tg-0lax") {
+                                    : "# Equivalent";
+                                }
                                 td(class="tg-0lax") {
                                     : "Score";
                                 }
@@ -74,22 +98,112 @@ pub fn build_html_page(data: &Vec<Mutation>, path: &Path) {
                                         : format!("{}", fm.iter().filter(|m| m.result == MutationResult::Killed).count());
                                     }
                                     td(class="tg-lax") {
-                                        : format!(
-                                            "{}%",
-                                            (fm.iter().filter(|m| m.result == MutationResult::Killed).count() as f32
-                                            / (fm.len() -
-                                                fm
-                                                .iter()
-                                                .filter(|m|
-                                            m.result != MutationResult::Killed &&
-                                            m.result != MutationResult::Survived)
-                                        .count()) as f32) * 100.0
-                                        );
+                                        : format!("{}", fm.iter().filter(|m| m.result == MutationResult::Equivalent).count());
+                                    }
+                                    td(class="tg-lax") {
+                                        : mutation_score(fm.iter().copied());
                                     }
                                 }
                             }
                         }
                     }
+                    table(class="tg") {
+                        tbody {
+                            tr {
+                                td(class="tg-0lax") {
+                                    : "Mutation Type";
+                                }
+                                td(class="tg-0lax") {
+                                    : "# of Mutations";
+                                }
+                                td(class="tg-0lax") {
+                                    : "# Survived";
+                                }
+                                td(class="tg-0lax") {
+                                    : "# Killed";
+                                }
+                                td(class="tg-0lax") {
+                                    : "# Equivalent";
+                                }
+                                td(class="tg-0lax") {
+                                    : "Score";
+                                }
+                            }
+                            @for (mutation_type, tm) in type_mutations.iter() {
+                                tr {
+                                    td(class="tg-ycr8") {
+                                        : format!("{}", mutation_type);
+                                    }
+                                    td(class="tg-lax") {
+                                        : format!("{}", tm.len());
+                                    }
+                                    td(class="tg-lax") {
+                                        : format!("{}", tm.iter().filter(|m| m.result == MutationResult::Survived).count());
+                                    }
+                                    td(class="tg-lax") {
+                                        : format!("{}", tm.iter().filter(|m| m.result == MutationResult::Killed).count());
+                                    }
+                                    td(class="tg-lax") {
+                                        : format!("{}", tm.iter().filter(|m| m.result == MutationResult::Equivalent).count());
+                                    }
+                                    td(class="tg-lax") {
+                                        : mutation_score(tm.iter().copied());
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    @if !module_mutations.is_empty() {
+                        table(class="tg") {
+                            tbody {
+                                tr {
+                                    td(class="tg-0lax") {
+                                        : "Module";
+                                    }
+                                    td(class="tg-0lax") {
+                                        : "# of Mutations";
+                                    }
+                                    td(class="tg-0lax") {
+                                        : "# Survived";
+                                    }
+                                    td(class="tg-0lax") {
+                                        : "# Killed";
+                                    }
+                                    td(class="tg-0lax") {
+                                        : "# Equivalent";
+                                    }
+                                    td(class="tg-0lax") {
+                                        : "Score";
+                                    }
+                                }
+                                @for (module, mm) in module_mutations.iter() {
+                                    tr {
+                                        td(class="tg-ycr8") {
+                                            : format!("{}", module);
+                                        }
+                                        td(class="tg-lax") {
+                                            : format!("{}", mm.len());
+                                        }
+                                        td(class="tg-lax") {
+                                            : format!("{}", mm.iter().filter(|m| m.result == MutationResult::Survived).count());
+                                        }
+                                        td(class="tg-lax") {
+                                            : format!("{}", mm.iter().filter(|m| m.result == MutationResult::Killed).count());
+                                        }
+                                        td(class="tg-lax") {
+                                            : format!("{}", mm.iter().filter(|m| m.result == MutationResult::Equivalent).count());
+                                        }
+                                        td(class="tg-lax") {
+                                            : mutation_score(mm.iter().copied());
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    p {
+                        : format!("Seed: {}", seed);
+                    }
                 }
             }
         }
@@ -107,7 +221,7 @@ mod test {
     use std::io::Read;
     use std::path::Path;
 
-    use crate::mutation_tool::{Mutation, MutationOperators, MutationResult};
+    use crate::mutation_tool::{mutation_score, Mutation, MutationOperators, MutationResult};
 
     use super::build_html_page;
 
@@ -141,15 +255,16 @@ mod test {
             MutationOperators::ElvisLiteralChangeOperator,
             "file2".to_string(),
         );
-        let mutation4 = Mutation::new(
+        let mut mutation4 = Mutation::new(
             0,
             12,
             "new_op4".to_string(),
             "old_op4".to_string(),
             3,
-            MutationOperators::LogicalReplacementOperator,
+            MutationOperators::CustomOperator("RequireNotNullOperator".into()),
             "file2".to_string(),
         );
+        mutation4.module = Some(":core:data".into());
 
         let mutations = vec![
             mutation1.clone(),
@@ -170,7 +285,7 @@ mod test {
         }
 
         // Call the function
-        build_html_page(&mutations, Path::new("./mutant-kraken-dist"));
+        build_html_page(&mutations, Path::new("./mutant-kraken-dist"), 42);
 
         // Read the generated HTML file
         let file_path = Path::new("mutant-kraken-dist").join("report.html");
@@ -183,13 +298,27 @@ mod test {
         // Verify that HTML content contains information about each file and mutation
         assert_contains(
             &file_content,
-            "<th class=\"tg-baqh\" colspan=\"5\">Mutant Kraken Results</th>",
+            "<th class=\"tg-baqh\" colspan=\"6\">Mutant Kraken Results</th>",
         );
         assert_contains(&file_content, "<td class=\"tg-0lax\">File Name</td>");
         assert_contains(&file_content, "<td class=\"tg-0lax\"># of Mutations</td>");
         assert_contains(&file_content, "<td class=\"tg-0lax\"># Survived</td>");
         assert_contains(&file_content, "<td class=\"tg-0lax\"># Killed</td>");
+        assert_contains(&file_content, "<td class=\"tg-0lax\"># Equivalent</td>");
         assert_contains(&file_content, "<td class=\"tg-0lax\">Score</td>");
+        assert_contains(&file_content, "<p>Seed: 42</p>");
+        assert_contains(&file_content, "<td class=\"tg-0lax\">Mutation Type</td>");
+        for mutation in &mutations {
+            assert_contains(
+                &file_content,
+                &format!("<td class=\"tg-ycr8\">{}</td>", mutation.mutation_type),
+            );
+        }
+        // Only the mutations with a module are listed in the module table
+        assert_contains(&file_content, "<td class=\"tg-0lax\">Module</td>");
+        assert_contains(&file_content, "<td class=\"tg-ycr8\">:core:data</td>");
+        // None of the mutants were killed or survived, so they have no score
+        assert_contains(&file_content, "<td class=\"tg-lax\">N/A</td>");
 
         for (file_name, fm) in file_mutations.iter() {
             assert_contains(
@@ -218,21 +347,9 @@ mod test {
                         .count()
                 ),
             );
-            let score = (fm
-                .iter()
-                .filter(|m| m.result == MutationResult::Killed)
-                .count() as f32
-                / (fm.len()
-                    - fm.iter()
-                        .filter(|m| {
-                            m.result != MutationResult::Killed
-                                && m.result != MutationResult::Survived
-                        })
-                        .count()) as f32)
-                * 100.0;
             assert_contains(
                 &file_content,
-                &format!("<td class=\"tg-lax\">{:.2}%</td>", score),
+                &format!("<td class=\"tg-lax\">{}</td>", mutation_score(fm)),
             );
         }
     }
@@ -1,6 +1,6 @@
 use std::{fmt, str::FromStr};
 
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng};
 
 use crate::error::{MutantKrakenError, Result};
 
@@ -63,12 +63,12 @@ impl KotlinExceptions {
         ]
     }
 
-    pub fn get_random_exception(&self) -> KotlinExceptions {
-        let mut rng = rand::thread_rng();
+    /// Picks another exception at random with the given random number generator
+    pub fn get_random_exception<R: Rng + ?Sized>(&self, rng: &mut R) -> KotlinExceptions {
         let exceptions = KotlinExceptions::get_all_exceptions();
         let mut rnd = self;
         while rnd == self {
-            rnd = exceptions.choose(&mut rng).unwrap();
+            rnd = exceptions.choose(rng).unwrap();
         }
 
         *rnd
@@ -79,9 +79,19 @@ mutant_kraken_macros::generate_kotlin_types_enum!();
 
 #[cfg(test)]
 mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
     use crate::kotlin_types::NON_NAMED_TYPES;
 
-    use super::KotlinTypes;
+    use super::{KotlinExceptions, KotlinTypes};
+
+    #[test]
+    fn should_pick_another_exception_with_the_rng() {
+        let exception = KotlinExceptions::NullPointerException;
+        let pick = |seed| exception.get_random_exception(&mut StdRng::seed_from_u64(seed));
+        assert_ne!(pick(1), exception);
+        assert_eq!(pick(1), pick(1));
+    }
 
     #[test]
     fn should_successfully_convert_kotlin_types() {
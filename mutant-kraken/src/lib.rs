@@ -1,7 +1,8 @@
+pub mod build_system;
 pub mod cli;
 pub mod config;
+pub mod coverage;
 pub mod error;
-pub mod gradle;
 pub mod html_gen;
 pub mod kotlin_types;
 pub mod mutation_tool;
@@ -1,13 +1,14 @@
 use std::path::Path;
 
-use crate::{cli::MutationCommandConfig, config::MutantKrakenConfig};
+use crate::{cli::MutationCommandConfig, config::MutantKrakenConfig, error::Result};
 
-use super::MutationTool;
+use super::{MutationOperator, MutationTool};
 
 pub struct MutationToolBuilder {
     mutate_config: Option<MutationCommandConfig>,
     mutantkraken_config: Option<MutantKrakenConfig>,
     enable_mutation_comment: bool,
+    custom_operators: Vec<Box<dyn MutationOperator>>,
 }
 
 impl Default for MutationToolBuilder {
@@ -23,6 +24,7 @@ impl MutationToolBuilder {
             mutate_config: None,
             mutantkraken_config: None,
             enable_mutation_comment: false,
+            custom_operators: vec![],
         }
     }
 
@@ -44,7 +46,16 @@ impl MutationToolBuilder {
         self
     }
 
-    pub fn build(self) -> MutationTool {
+    /// Adds an operator implemented outside of this crate.
+    /// It runs alongside the operators set in the general config
+    pub fn add_custom_operator(mut self, operator: Box<dyn MutationOperator>) -> Self {
+        self.custom_operators.push(operator);
+        self
+    }
+
+    /// Builds the mutation tool. Fails when the output directories can not be created, or when a
+    /// custom operator of the config has a query file that can not be read or compiled
+    pub fn build(self) -> Result<MutationTool> {
         let mutate_config = self.mutate_config.unwrap_or_default();
         let mutantkraken_config = self.mutantkraken_config.unwrap_or_default();
 
@@ -58,14 +69,17 @@ impl MutationToolBuilder {
             mutantkraken_config.clone(),
             output_directory,
             mutantkraken_config.general.operators.clone(),
+            self.custom_operators,
             self.enable_mutation_comment,
         )
-        .unwrap()
     }
 }
 #[cfg(test)]
 mod tests {
-    use crate::{config::GeneralConfig, mutation_tool::MutationOperators};
+    use crate::{
+        config::{CustomOperatorConfig, GeneralConfig},
+        mutation_tool::MutationOperators,
+    };
     use std::env::temp_dir;
 
     use super::*;
@@ -81,16 +95,18 @@ mod tests {
 
         let builder = MutationToolBuilder::new().set_mutate_config(MutationCommandConfig {
             path: temp_dir.to_str().unwrap().to_string(),
+            seed: None,
         });
-        let mutation_tool = builder.build();
+        let mutation_tool = builder.build().unwrap();
 
         // Add assertions based on your specific default values
-        assert_eq!(mutation_tool.enable_mutation_comment, false);
+        assert!(!mutation_tool.enable_mutation_comment);
         assert_eq!(mutation_tool.mutantkraken_config, MutantKrakenConfig::new());
         assert_eq!(
             mutation_tool.mutate_config,
             MutationCommandConfig {
                 path: temp_dir.to_str().unwrap().to_string(),
+                seed: None,
             }
         );
         assert_eq!(
@@ -110,7 +126,22 @@ mod tests {
                 WhenRemoveBranchOperator,
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
-                FunctionalReplacementOperator
+                FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator
             ]
         );
     }
@@ -129,6 +160,7 @@ mod tests {
                     MutationOperators::AssignmentReplacementOperator,
                     MutationOperators::UnaryRemovalOperator,
                 ],
+                ..Default::default()
             },
             ..Default::default()
         };
@@ -136,9 +168,10 @@ mod tests {
         let builder = MutationToolBuilder::new()
             .set_mutate_config(MutationCommandConfig {
                 path: temp_dir.to_str().unwrap().to_string(),
+                seed: None,
             })
             .set_general_config(general_config.clone());
-        let mutation_tool = builder.build();
+        let mutation_tool = builder.build().unwrap();
 
         assert_eq!(mutation_tool.mutantkraken_config.general.timeout, Some(10));
         assert_eq!(
@@ -159,10 +192,11 @@ mod tests {
 
         let mutate_config = MutationCommandConfig {
             path: temp_dir.to_str().unwrap().to_string(),
+            seed: None,
         };
 
         let builder = MutationToolBuilder::new().set_mutate_config(mutate_config.clone());
-        let mutation_tool = builder.build();
+        let mutation_tool = builder.build().unwrap();
 
         // Add assertions based on your specific mutate_config fields
         assert_eq!(mutation_tool.mutate_config, mutate_config);
@@ -181,11 +215,12 @@ mod tests {
         let builder = MutationToolBuilder::new()
             .set_mutate_config(MutationCommandConfig {
                 path: temp_dir.to_str().unwrap().to_string(),
+                seed: None,
             })
             .set_mutation_comment(true);
-        let mutation_tool = builder.build();
+        let mutation_tool = builder.build().unwrap();
 
-        assert_eq!(mutation_tool.enable_mutation_comment, true);
+        assert!(mutation_tool.enable_mutation_comment);
     }
 
     #[test]
@@ -197,11 +232,12 @@ mod tests {
 
         let builder = MutationToolBuilder::new().set_mutate_config(MutationCommandConfig {
             path: temp_dir.to_str().unwrap().to_string(),
+            seed: None,
         });
-        let mutation_tool = builder.build();
+        let mutation_tool = builder.build().unwrap();
 
         // Add assertions based on your specific default values
-        assert_eq!(mutation_tool.enable_mutation_comment, false);
+        assert!(!mutation_tool.enable_mutation_comment);
         assert_eq!(
             mutation_tool.mutantkraken_config,
             MutantKrakenConfig::default()
@@ -210,6 +246,7 @@ mod tests {
             mutation_tool.mutate_config,
             MutationCommandConfig {
                 path: temp_dir.to_str().unwrap().to_string(),
+                seed: None,
             }
         );
         assert_eq!(
@@ -229,8 +266,83 @@ mod tests {
                 WhenRemoveBranchOperator,
                 RemoveLabelOperator,
                 FunctionalBinaryReplacementOperator,
-                FunctionalReplacementOperator
+                FunctionalReplacementOperator,
+                ConditionNegationOperator,
+                ReturnValueOperator,
+                StatementRemovalOperator,
+                NullSafetyOperator,
+                CollectionOperator,
+                RangeBoundaryOperator,
+                StringOperator,
+                ScopeFunctionOperator,
+                OverrideSuperCallOperator,
+                DataClassPropertyOperator,
+                InitBlockRemovalOperator,
+                EmptyCatchOperator,
+                FinallyRemovalOperator,
+                ThrowRemovalOperator,
+                RunCatchingOperator
             ]
         );
     }
+
+    struct RemoveTodoOperator;
+
+    impl MutationOperator for RemoveTodoOperator {
+        fn name(&self) -> String {
+            "RemoveTodoOperator".into()
+        }
+
+        fn matches(&self, node: &tree_sitter::Node, source: &str) -> bool {
+            node.kind() == "call_expression" && source[node.byte_range()].starts_with("TODO(")
+        }
+
+        fn mutate(&self, _node: &tree_sitter::Node, _source: &str) -> Vec<String> {
+            vec!["Unit".into()]
+        }
+    }
+
+    #[test]
+    fn test_build_fails_for_invalid_custom_operator() {
+        let temp_dir = temp_dir().join("invalid_custom_operator");
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        std::fs::write(temp_dir.join("invalid.scm"), "((simple_identifier) @fn").unwrap();
+        let mut config = MutantKrakenConfig::default();
+        for query_file in ["missing.scm", "invalid.scm"] {
+            config.general.custom_operators = vec![CustomOperatorConfig {
+                name: "InvalidOperator".into(),
+                query_file: query_file.into(),
+                replacement: "print".into(),
+                capture: None,
+            }];
+            let builder = MutationToolBuilder::new()
+                .set_mutate_config(MutationCommandConfig {
+                    path: temp_dir.to_str().unwrap().to_string(),
+                    seed: None,
+                })
+                .set_general_config(config.clone());
+            assert!(builder.build().is_err(), "Built with {}", query_file);
+        }
+    }
+
+    #[test]
+    fn test_add_custom_operator() {
+        // Create a temp directory
+        let temp_dir = temp_dir().join("add_custom_operator");
+        // Create the temp directory
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let builder = MutationToolBuilder::new()
+            .set_mutate_config(MutationCommandConfig {
+                path: temp_dir.to_str().unwrap().to_string(),
+                seed: None,
+            })
+            .add_custom_operator(Box::new(RemoveTodoOperator));
+        let mutation_tool = builder.build().unwrap();
+
+        assert_eq!(mutation_tool.custom_operators.len(), 1);
+        assert_eq!(
+            mutation_tool.custom_operators[0].mutation_type(),
+            CustomOperator("RemoveTodoOperator".into())
+        );
+    }
 }
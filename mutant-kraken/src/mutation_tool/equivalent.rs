@@ -0,0 +1,480 @@
+use tree_sitter::Node;
+
+use crate::{
+    config::GeneralConfig,
+    mutation_tool::{Mutation, MutationOperators},
+};
+
+/// The functions that only print a value
+const PRINT_FUNCTIONS: [&str; 2] = ["println", "print"];
+
+/// The functions of a logger that only log a value
+const LOG_FUNCTIONS: [&str; 13] = [
+    "trace", "debug", "info", "warn", "warning", "error", "fatal", "v", "d", "i", "w", "e", "wtf",
+];
+
+/// Checks if the mutation can never be killed by a test.
+///
+/// A mutation is equivalent when:
+///
+/// - It changes `x + 0` to `x - 0` or `x * 1` to `x / 1`, or the other way around
+/// - It changes a literal that is only printed or logged
+/// - It changes `<` to `!=` in a `while` loop that counts up by one from 0 to a bound that is
+///   never negative
+/// - It is made inside `override fun toString()`, when `to_string_equivalent` is set in the config
+///
+/// # Arguments
+///
+/// * `mutation` - The mutation to check.
+/// * `root` - The root node of the AST of the file the mutation was made to.
+/// * `source` - The source code of the file the mutation was made to.
+/// * `config` - The `GeneralConfig`, which sets if mutants in `toString` are equivalent.
+pub fn is_equivalent(
+    mutation: &Mutation,
+    root: &Node,
+    source: &str,
+    config: &GeneralConfig,
+) -> bool {
+    let node = match root.descendant_for_byte_range(mutation.start_byte, mutation.end_byte) {
+        Some(node) => node,
+        None => return false,
+    };
+    let equivalent = match mutation.mutation_type {
+        MutationOperators::ArithmeticReplacementOperator => {
+            is_identity_arithmetic(mutation, &node, source)
+        }
+        MutationOperators::LiteralChangeOperator => is_only_logged(&node, source),
+        MutationOperators::RelationalReplacementOperator => {
+            is_counting_loop_bound(mutation, &node, source)
+        }
+        _ => false,
+    };
+    equivalent || (config.to_string_equivalent && is_in_to_string(&node, source))
+}
+
+fn text<'a>(node: &Node, source: &'a str) -> &'a str {
+    source.get(node.byte_range()).unwrap_or_default()
+}
+
+/// Checks if the mutation swaps the operator of an addition of 0 or a multiplication by 1
+fn is_identity_arithmetic(mutation: &Mutation, node: &Node, source: &str) -> bool {
+    let identity = match (mutation.old_op.as_str(), mutation.new_op.as_str()) {
+        ("+", "-") | ("-", "+") => 0.0,
+        ("*", "/") | ("/", "*") => 1.0,
+        _ => return false,
+    };
+    let right = node
+        .parent()
+        .and_then(|parent| parent.named_child(parent.named_child_count().saturating_sub(1)));
+    match right {
+        Some(right) if right.kind().ends_with("_literal") => {
+            let value = text(&right, source).trim_end_matches(['L', 'f', 'F']);
+            value.parse::<f64>().is_ok_and(|value| value == identity)
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the literal is only passed to print or log functions, either directly
+/// or through a local value
+fn is_only_logged(node: &Node, source: &str) -> bool {
+    if is_in_log_call(node, source) {
+        return true;
+    }
+
+    // The literal is the value of a local `val`
+    let declaration = match node.parent() {
+        Some(parent) if parent.kind() == "property_declaration" => parent,
+        _ => return false,
+    };
+    let is_val = declaration
+        .child(0)
+        .is_some_and(|keyword| keyword.kind() == "val");
+    let name = declaration
+        .children(&mut declaration.walk())
+        .find(|c| c.kind() == "variable_declaration")
+        .and_then(|c| c.named_child(0))
+        .map(|c| text(&c, source));
+    let block = declaration.parent();
+    let (Some(name), Some(block), true) = (name, block, is_val) else {
+        return false;
+    };
+    if block.kind() != "statements" {
+        return false;
+    }
+
+    // Every use of the value after the declaration is logged
+    let mut uses = Vec::new();
+    find_identifiers(&block, name, source, &mut uses);
+    uses.iter()
+        .filter(|u| u.start_byte() > declaration.end_byte())
+        .all(|u| is_in_log_call(u, source))
+}
+
+/// Finds every identifier with the given name in `node`
+fn find_identifiers<'tree>(
+    node: &Node<'tree>,
+    name: &str,
+    source: &str,
+    found: &mut Vec<Node<'tree>>,
+) {
+    if node.kind() == "simple_identifier" && text(node, source) == name {
+        found.push(*node);
+    }
+    for child in node.children(&mut node.walk()) {
+        find_identifiers(&child, name, source, found);
+    }
+}
+
+/// Checks if the node is an argument of a print or log call
+fn is_in_log_call(node: &Node, source: &str) -> bool {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "value_argument"
+            | "value_arguments"
+            | "call_suffix"
+            | "additive_expression"
+            | "string_literal"
+            | "interpolated_expression"
+            | "parenthesized_expression" => {}
+            "call_expression" => return is_log_function(&parent, source),
+            _ => return false,
+        }
+        current = parent;
+    }
+    false
+}
+
+/// Checks if the call is a call to `println` or `print`, or a call to a logger like `logger.info`
+fn is_log_function(call: &Node, source: &str) -> bool {
+    let callee = match call.named_child(0) {
+        Some(callee) => callee,
+        None => return false,
+    };
+    match callee.kind() {
+        "simple_identifier" => PRINT_FUNCTIONS.contains(&text(&callee, source)),
+        "navigation_expression" => {
+            let receiver = callee.named_child(0).map(|r| text(&r, source));
+            let function = callee
+                .named_child(1)
+                .and_then(|suffix| suffix.named_child(0))
+                .map(|f| text(&f, source));
+            match (receiver, function) {
+                (Some(receiver), Some(function)) => {
+                    (receiver.to_lowercase().contains("log") || receiver == "Timber")
+                        && LOG_FUNCTIONS.contains(&function)
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the mutation changes `i < n` to `i != n` in the condition of a `while` loop, where
+/// `i` starts at 0 and the loop only ever increments it by one. The bound has to be a size or a
+/// non-negative literal, as a loop with a negative bound would never stop after the mutation
+fn is_counting_loop_bound(mutation: &Mutation, node: &Node, source: &str) -> bool {
+    if mutation.old_op != "<" || mutation.new_op != "!=" {
+        return false;
+    }
+    let comparison = match node.parent() {
+        Some(comparison) => comparison,
+        None => return false,
+    };
+    let (Some(counter), Some(loop_node)) = (comparison.named_child(0), comparison.parent()) else {
+        return false;
+    };
+    if counter.kind() != "simple_identifier" || loop_node.kind() != "while_statement" {
+        return false;
+    }
+    if !comparison
+        .named_child(1)
+        .is_some_and(|bound| is_non_negative(&bound, source))
+    {
+        return false;
+    }
+    let name = text(&counter, source);
+
+    // The counter is declared as `var i = 0` before the loop
+    let starts_at_zero =
+        std::iter::successors(loop_node.prev_named_sibling(), |n| n.prev_named_sibling())
+            .find(|n| {
+                n.kind() == "property_declaration"
+                    && n.children(&mut n.walk())
+                        .find(|c| c.kind() == "variable_declaration")
+                        .and_then(|c| c.named_child(0))
+                        .is_some_and(|c| text(&c, source) == name)
+            })
+            .and_then(|declaration| declaration.named_child(declaration.named_child_count() - 1))
+            .is_some_and(|value| value.kind() == "integer_literal" && text(&value, source) == "0");
+    if !starts_at_zero {
+        return false;
+    }
+
+    // The body changes the counter exactly once, by incrementing it
+    let body = match loop_node.named_child(loop_node.named_child_count() - 1) {
+        Some(body) => body,
+        None => return false,
+    };
+    let mut uses = Vec::new();
+    find_identifiers(&body, name, source, &mut uses);
+    let updates = uses
+        .iter()
+        .filter_map(|u| {
+            let parent = u.parent()?;
+            match parent.kind() {
+                "postfix_expression" | "prefix_expression" | "directly_assignable_expression" => {
+                    Some(parent)
+                }
+                _ => None,
+            }
+        })
+        .map(|update| match update.kind() {
+            "directly_assignable_expression" => update.parent().map_or(String::new(), |a| {
+                text(&a, source)
+                    .chars()
+                    .filter(|c| !c.is_whitespace())
+                    .collect()
+            }),
+            _ => text(&update, source).to_string(),
+        })
+        .collect::<Vec<_>>();
+    updates.len() == 1
+        && [
+            format!("{}++", name),
+            format!("++{}", name),
+            format!("{}+=1", name),
+        ]
+        .contains(&updates[0])
+}
+
+/// Checks if the expression can never be negative: a non-negative integer literal, or the `size`,
+/// `length` or `count()` of a collection or string
+fn is_non_negative(node: &Node, source: &str) -> bool {
+    match node.kind() {
+        "integer_literal" => true,
+        "navigation_expression" => node
+            .named_child(node.named_child_count().saturating_sub(1))
+            .is_some_and(|suffix| matches!(text(&suffix, source), ".size" | ".length")),
+        "call_expression" => text(node, source).ends_with(".count()"),
+        _ => false,
+    }
+}
+
+/// Checks if the node is inside an `override fun toString()`
+fn is_in_to_string(node: &Node, source: &str) -> bool {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        if parent.kind() == "function_declaration" {
+            let child = |kind| {
+                parent
+                    .children(&mut parent.walk())
+                    .find(|c| c.kind() == kind)
+                    .map(|c| text(&c, source))
+            };
+            return child("simple_identifier") == Some("toString")
+                && child("function_value_parameters") == Some("()")
+                && child("modifiers").is_some_and(|modifiers| {
+                    modifiers.split_whitespace().any(|m| m == "override")
+                });
+        }
+        current = parent;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EQUIVALENT_TEST_CODE: &str = r#"
+class Counter(val start: Int) {
+    fun count(n: Int, items: IntArray): Int {
+        var total = start + 0
+        total = total * 1
+        total = total - 1
+        val retries = 3
+        logger.info("Retrying", retries)
+        println(42)
+        var i = 0
+        while (i < n) {
+            total += i
+            i++
+        }
+        var j = 0
+        while (j < n) {
+            j += 2
+        }
+        var k = 0
+        while (k < items.size) {
+            total += items[k]
+            k++
+        }
+        var l = 0
+        while (l < 10) {
+            l += 1
+        }
+        return total + 7
+    }
+
+    override fun toString(): String {
+        return "Counter" + 1
+    }
+
+    fun toString(prefix: String): String {
+        return prefix + "Counter"
+    }
+}
+"#;
+
+    /// Checks if the mutation of the nth occurrence of `old_op` to `new_op` is equivalent
+    fn is_equivalent_at(
+        old_op: &str,
+        occurrence: usize,
+        new_op: &str,
+        mutation_type: MutationOperators,
+    ) -> bool {
+        is_equivalent_with(
+            &GeneralConfig::default(),
+            old_op,
+            occurrence,
+            new_op,
+            mutation_type,
+        )
+    }
+
+    /// Checks if the mutation is equivalent with the given config
+    fn is_equivalent_with(
+        config: &GeneralConfig,
+        old_op: &str,
+        occurrence: usize,
+        new_op: &str,
+        mutation_type: MutationOperators,
+    ) -> bool {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_kotlin::language())
+            .unwrap();
+        let tree = parser.parse(EQUIVALENT_TEST_CODE, None).unwrap();
+        let start = EQUIVALENT_TEST_CODE
+            .match_indices(old_op)
+            .nth(occurrence)
+            .unwrap()
+            .0;
+        let mutation = Mutation::new(
+            start,
+            start + old_op.len(),
+            new_op.into(),
+            old_op.into(),
+            1,
+            mutation_type,
+            "Counter.kt".into(),
+        );
+        is_equivalent(&mutation, &tree.root_node(), EQUIVALENT_TEST_CODE, config)
+    }
+
+    #[test]
+    fn test_identity_arithmetic_is_equivalent() {
+        use MutationOperators::ArithmeticReplacementOperator;
+        assert!(is_equivalent_at("+", 0, "-", ArithmeticReplacementOperator));
+        assert!(is_equivalent_at("*", 0, "/", ArithmeticReplacementOperator));
+        assert!(!is_equivalent_at(
+            "*",
+            0,
+            "-",
+            ArithmeticReplacementOperator
+        ));
+        assert!(!is_equivalent_at(
+            "-",
+            0,
+            "+",
+            ArithmeticReplacementOperator
+        ));
+        assert!(!is_equivalent_at(
+            "+",
+            3,
+            "-",
+            ArithmeticReplacementOperator
+        ));
+    }
+
+    #[test]
+    fn test_logged_literal_is_equivalent() {
+        use MutationOperators::LiteralChangeOperator;
+        assert!(is_equivalent_at("3", 0, "0", LiteralChangeOperator));
+        assert!(is_equivalent_at("42", 0, "0", LiteralChangeOperator));
+        assert!(!is_equivalent_at("7", 0, "0", LiteralChangeOperator));
+    }
+
+    #[test]
+    fn test_counting_loop_bound_is_equivalent() {
+        use MutationOperators::RelationalReplacementOperator;
+        assert!(is_equivalent_at(
+            "<",
+            2,
+            "!=",
+            RelationalReplacementOperator
+        ));
+        assert!(is_equivalent_at(
+            "<",
+            3,
+            "!=",
+            RelationalReplacementOperator
+        ));
+        assert!(!is_equivalent_at(
+            "<",
+            2,
+            "<=",
+            RelationalReplacementOperator
+        ));
+        // The bound of the first loop may be negative
+        assert!(!is_equivalent_at(
+            "<",
+            0,
+            "!=",
+            RelationalReplacementOperator
+        ));
+        // The second loop counts up by two
+        assert!(!is_equivalent_at(
+            "<",
+            1,
+            "!=",
+            RelationalReplacementOperator
+        ));
+    }
+
+    #[test]
+    fn test_mutation_in_to_string_is_equivalent() {
+        use MutationOperators::StringOperator;
+        let config = GeneralConfig {
+            to_string_equivalent: true,
+            ..Default::default()
+        };
+        assert!(is_equivalent_with(
+            &config,
+            "\"Counter\"",
+            0,
+            "\"\"",
+            StringOperator
+        ));
+        assert!(!is_equivalent_with(
+            &config,
+            "\"Retrying\"",
+            0,
+            "\"\"",
+            StringOperator
+        ));
+        // The function does not override toString
+        assert!(!is_equivalent_with(
+            &config,
+            "\"Counter\"",
+            1,
+            "\"\"",
+            StringOperator
+        ));
+        // Tests can check the text of an object, so the mutants are only equivalent when set
+        assert!(!is_equivalent_at("\"Counter\"", 0, "\"\"", StringOperator));
+    }
+}
@@ -1,11 +1,19 @@
 mod builder;
+mod equivalent;
 mod mutation;
+mod mutation_operator;
 mod operators;
+mod query_operator;
+mod symbol_table;
 mod tool;
 
 pub use builder::*;
+pub use equivalent::*;
 pub use mutation::*;
+pub use mutation_operator::*;
 pub use operators::*;
+pub use query_operator::*;
+pub use symbol_table::*;
 pub use tool::*;
 
 pub fn debug_print_ast(ast: &tree_sitter::Node, spaces: usize) {
@@ -249,5 +257,254 @@ fun main() {
         // Using ForEach() to print each number
         numbers.forEach { println(it) }
     }
+"#;
+
+    pub const KOTLIN_CONDITION_NEGATION_TEST_CODE: &str = r#"
+fun main() {
+    val a = 10
+    val b = 3
+    if (a > b) {
+        println("a is greater")
+    } else if (a == b) {
+        println("a is equal")
+    }
+    var i = 0
+    while (i < a) {
+        i++
+    }
+    do {
+        i--
+    } while (i > 0)
+    if (true) {
+        println("always")
+    }
+}
+"#;
+
+    pub const KOTLIN_RETURN_VALUE_TEST_CODE: &str = r#"
+class Values {
+    fun nullable(): String? {
+        return "value"
+    }
+
+    fun int(): Int = 42
+
+    fun long(): Long {
+        return 42L
+    }
+
+    fun double(): kotlin.Double = 4.2
+
+    fun string(): String = "value"
+
+    fun boolean(flag: Boolean): Boolean {
+        if (flag) {
+            return true
+        }
+        return false
+    }
+
+    fun list(): List<Int> {
+        val inner = fun(): Int { return 1 }
+        return listOf(inner())
+    }
+
+    fun map(): Map<String, Int> = mapOf("a" to 1)
+
+    fun set(): Set<Int> = setOf(1)
+
+    fun unit() {
+        return
+    }
+}
+"#;
+
+    pub const KOTLIN_STATEMENT_REMOVAL_TEST_CODE: &str = r#"
+fun main() {
+    val logger = Logger()
+    val list = mutableListOf<Int>()
+    logger.log("start")
+    list.add(1)
+    val size = list.size
+    validate(list)
+    val result = compute(list)
+    println(validate(list))
+    val valid = run {
+        logger.log("validating")
+        validate(list)
+    }
+    val checked = if (valid) { validate(list) } else { list.clear() }
+    val parsed = when (size) {
+        0 -> { compute(list) }
+        else -> try { compute(list) } catch (e: Exception) { compute(list) }
+    }
+    if (valid) {
+        logger.log("valid")
+    } else {
+        list.clear()
+    }
+    return finish(result)
+}
+"#;
+
+    pub const KOTLIN_NULL_SAFETY_TEST_CODE: &str = r#"
+fun main(name: String?, fallback: String?) {
+    name?.let { println(it) }
+    val trimmed = name?.trim()
+    val length = name?.length
+    val a = name ?: return
+    val b = fallback ?: throw IllegalStateException("No name")
+    val c = fallback ?: "default"
+}
+"#;
+
+    pub const KOTLIN_COLLECTION_TEST_CODE: &str = r#"
+fun main(names: List<String>?) {
+    val numbers = listOf(1, 2, 3)
+    val empty = numbers.isEmpty()
+    val notEmpty = numbers.isNotEmpty()
+    val nullOrEmpty = names.isNullOrEmpty()
+    val safeNames = names.orEmpty()
+    val hasNumbers = numbers.size > 0
+    val small = numbers.size <= 3
+    val larger = numbers.first() > 1
+}
+"#;
+
+    pub const KOTLIN_RANGE_BOUNDARY_TEST_CODE: &str = r#"
+fun main(n: Int) {
+    for (i in 0..n) {
+        println(i)
+    }
+    for (j in 0 until n) {
+        println(j)
+    }
+    for (k in n downTo 1) {
+        println(k)
+    }
+    for (l in 0..10 step 2) {
+        println(l)
+    }
+}
+"#;
+
+    pub const KOTLIN_STRING_TEST_CODE: &str = r#"
+fun main(name: String, place: String) {
+    val empty = ""
+    val kraken = "Kraken"
+    val greeting = "Hello ${name.trim()} from $place!"
+    val shout = greeting.uppercase()
+    val startsWithH = greeting.startsWith("H")
+}
+"#;
+
+    pub const KOTLIN_SCOPE_FUNCTION_TEST_CODE: &str = r#"
+fun main() {
+    val builder = StringBuilder().apply { append("a") }
+    val list = mutableListOf(1).also { it.add(2) }
+    val length = builder.let { it.length }
+    val size = list.run { size }
+    val positive = size.takeIf { it > 0 }
+    val negative = size.takeUnless { it > 0 }
+    with(builder) { append("b") }
+    val logged = list.also { println("logged") }
+    val copy = builder.let { list.size }
+    val added = builder.apply { list.add(3) }
+    val count = list.run { builder.length }
+}
+"#;
+
+    pub const KOTLIN_CLASS_DECLARATION_TEST_CODE: &str = r#"
+fun main() {
+    data class Point(val x: Int, var y: Int = 0, z: Int) {
+        init { require(x > 0) }
+
+        override fun equals(other: Any?): Boolean {
+            return other is Point && other.x == x
+        }
+
+        override fun hashCode(): Int = x
+
+        override fun toString() = "Point($x, $y)"
+
+        fun equals(): Boolean = false
+    }
+
+    data class User(val id: Int, val name: String)
+
+    data class Single(val value: Int)
+}
+"#;
+
+    pub const KOTLIN_COROUTINE_TEST_CODE: &str = r#"
+suspend fun main() {
+    coroutineScope {
+        val job = launch { work() }
+        val deferred = async { compute() }
+        withContext(Dispatchers.IO) {
+            yield()
+            coroutineContext.ensureActive()
+            ensureActive(job)
+        }
+        withTimeout(Dispatchers.Main) { }
+        val result = deferred.await()
+        job.join()
+        job.join(timeout)
+    }
+}
+"#;
+
+    pub const KOTLIN_EXCEPTION_HANDLING_TEST_CODE: &str = r#"
+fun main() {
+    try {
+        open()
+    } catch (e: IOException) {
+        log(e)
+        retry()
+    } catch (e: Exception) {
+    } finally {
+        close()
+    }
+    try {
+        open()
+    } finally {
+        close()
+    }
+    if (x) throw IllegalStateException("bad")
+    val y = x ?: throw IllegalStateException()
+    val w = if (x) 1 else throw IllegalArgumentException()
+    fun fail(): Int = throw IllegalStateException()
+    val r = runCatching { open() }.getOrThrow()
+    val z = result.getOrThrow()
+}
+"#;
+
+    pub const KOTLIN_WHEN_STATEMENT_TEST_CODE: &str = r#"
+fun main() {
+    val a = 10
+    when (a) {
+        1 -> println("one")
+        2 -> println("two")
+        else -> println("other")
+    }
+    val b = when (a) {
+        1 -> "one"
+        else -> "other"
+    }
+}
+"#;
+
+    pub const KOTLIN_TYPED_TEST_CODE: &str = r#"
+fun greet(name: String, times: Int, loud: Boolean): String {
+    var message = "Hello, " + name
+    message += "!"
+    var count = times
+    count += 1
+    var done = !loud
+    done = true
+    var total = count * 2
+    ++total
+    return message
+}
 "#;
 }
@@ -3,16 +3,21 @@ use std::fmt::Display;
 use cli_table::Table;
 use uuid::Uuid;
 
-use crate::mutation_tool::MutationOperators;
+use crate::{kotlin_types::KotlinTypes, mutation_tool::MutationOperators};
 
-#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, serde::Serialize, PartialEq, Eq, Default)]
 pub enum MutationResult {
+    #[default]
     InProgress,
     Survived,
     Killed,
     BuildFailed,
     Timeout,
     Failed,
+    /// The mutated source does not parse, so it was not built
+    Invalid,
+    /// The mutant can never be killed, so it was not built
+    Equivalent,
 }
 
 impl Display for MutationResult {
@@ -24,16 +29,12 @@ impl Display for MutationResult {
             MutationResult::BuildFailed => write!(f, "Build Failed"),
             MutationResult::Timeout => write!(f, "Timeout"),
             MutationResult::Failed => write!(f, "Failed"),
+            MutationResult::Invalid => write!(f, "Invalid"),
+            MutationResult::Equivalent => write!(f, "Equivalent"),
         }
     }
 }
 
-impl Default for MutationResult {
-    fn default() -> Self {
-        Self::InProgress
-    }
-}
-
 #[derive(Debug, Clone, Table, serde::Serialize)]
 /// Represents a mutation applied to a code file.
 pub struct Mutation {
@@ -67,6 +68,9 @@ pub struct Mutation {
     /// The result of the mutation.
     #[table(title = "Result")]
     pub result: MutationResult,
+    /// The module of the project the mutated file is in, for projects with modules.
+    #[table(skip)]
+    pub module: Option<String>,
 }
 
 impl Mutation {
@@ -89,6 +93,17 @@ impl Mutation {
             id: Uuid::new_v4(),
             result: MutationResult::default(),
             file_name,
+            module: None,
+        }
+    }
+
+    /// Gets the text that replaces the old operator in the mutated file.
+    /// Removal mutations replace the old operator with nothing.
+    pub fn replacement(&self) -> &str {
+        if self.new_op == KotlinTypes::RemoveOperator.to_string() {
+            ""
+        } else {
+            &self.new_op
         }
     }
 }
@@ -119,6 +134,24 @@ impl Display for Mutation {
 pub struct FileMutations {
     pub mutations: Vec<Mutation>,
 }
+
+/// Gets the mutation score of the mutations, the percentage of the killed and survived mutants
+/// that were killed. It is `N/A` when no mutant was killed or survived
+pub fn mutation_score<'a>(mutations: impl IntoIterator<Item = &'a Mutation>) -> String {
+    let (killed, survived) = mutations
+        .into_iter()
+        .fold((0, 0), |(killed, survived), mutation| {
+            match mutation.result {
+                MutationResult::Killed => (killed + 1, survived),
+                MutationResult::Survived => (killed, survived + 1),
+                _ => (killed, survived),
+            }
+        });
+    if killed + survived == 0 {
+        return "N/A".into();
+    }
+    format!("{}%", (killed as f32 / (killed + survived) as f32) * 100.0)
+}
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +169,33 @@ mod tests {
         assert_eq!(formatted_result, "Timeout");
     }
 
+    #[test]
+    fn test_mutation_score() {
+        let mutation = |result| Mutation {
+            result,
+            ..Mutation::new(
+                0,
+                1,
+                "-".into(),
+                "+".into(),
+                1,
+                MutationOperators::ArithmeticReplacementOperator,
+                "Main.kt".into(),
+            )
+        };
+        let mutations = [
+            mutation(MutationResult::Killed),
+            mutation(MutationResult::Survived),
+            mutation(MutationResult::Killed),
+            mutation(MutationResult::Killed),
+            mutation(MutationResult::Equivalent),
+        ];
+        assert_eq!(mutation_score(&mutations), "75%");
+        // Invalid, equivalent and build failed mutants are not scored
+        assert_eq!(mutation_score(&mutations[4..]), "N/A");
+        assert_eq!(mutation_score(&[]), "N/A");
+    }
+
     #[test]
     fn test_create_mutation() {
         let mutation = Mutation::new(
@@ -191,6 +251,36 @@ mod tests {
         assert_eq!(format!("{}", mutation), expected_output);
     }
 
+    #[test]
+    fn test_replacement_of_remove_operator_is_empty() {
+        let mutation = Mutation::new(
+            10, // start_byte
+            20, // end_byte
+            KotlinTypes::RemoveOperator.to_string(),
+            "old_op".to_string(),
+            42, // line_number
+            MutationOperators::StatementRemovalOperator,
+            "example.rs".to_string(),
+        );
+
+        assert_eq!(mutation.replacement(), "");
+    }
+
+    #[test]
+    fn test_replacement_of_new_operator() {
+        let mutation = Mutation::new(
+            10, // start_byte
+            20, // end_byte
+            "new_op".to_string(),
+            "old_op".to_string(),
+            42, // line_number
+            MutationOperators::ArithmeticReplacementOperator,
+            "example.rs".to_string(),
+        );
+
+        assert_eq!(mutation.replacement(), "new_op");
+    }
+
     #[test]
     fn test_create_file_mutations() {
         let mutations = vec![
@@ -0,0 +1,140 @@
+use crate::mutation_tool::{Mutation, MutationOperators};
+
+/// A mutation operator that can be implemented outside of this crate.
+///
+/// The built-in operators are the variants of `MutationOperators`. Other operators implement this
+/// trait and are passed to the tool with `MutationToolBuilder::add_custom_operator`.
+/// Their mutations are reported under the name returned by `name`.
+pub trait MutationOperator: Send + Sync {
+    /// The name the mutations of the operator are reported under
+    fn name(&self) -> String;
+
+    /// Checks if the operator mutates the given node
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to check.
+    /// * `source` - The source code of the file the node is in.
+    fn matches(&self, node: &tree_sitter::Node, source: &str) -> bool;
+
+    /// Creates the mutants for a node that matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `node` - The node to mutate.
+    /// * `source` - The source code of the file the node is in.
+    ///
+    /// # Returns
+    ///
+    /// The text that replaces the node, one for each mutant.
+    fn mutate(&self, node: &tree_sitter::Node, source: &str) -> Vec<String>;
+
+    /// Gets the mutation type the mutations of the operator are reported under
+    fn mutation_type(&self) -> MutationOperators {
+        MutationOperators::CustomOperator(self.name())
+    }
+
+    /// Gets all the mutations the operator makes to the file.
+    ///
+    /// Visits every node of the tree, and creates a mutation for each replacement returned by
+    /// `mutate` for the nodes that match.
+    fn find_mutation(
+        &self,
+        ast: &tree_sitter::Tree,
+        file_name: &str,
+        source: &str,
+    ) -> Vec<Mutation> {
+        let mut mutations = Vec::new();
+        let mut cursor = ast.walk();
+        loop {
+            let node = cursor.node();
+            if self.matches(&node, source) {
+                let old_op = &source[node.start_byte()..node.end_byte()];
+                for new_op in self.mutate(&node, source) {
+                    if new_op == old_op {
+                        continue;
+                    }
+                    mutations.push(Mutation::new(
+                        node.start_byte(),
+                        node.end_byte(),
+                        new_op,
+                        old_op.to_string(),
+                        node.start_position().row + 1,
+                        self.mutation_type(),
+                        file_name.to_string(),
+                    ));
+                }
+            }
+
+            // Visit the nodes depth first
+            if cursor.goto_first_child() || cursor.goto_next_sibling() {
+                continue;
+            }
+            loop {
+                if !cursor.goto_parent() {
+                    return mutations;
+                }
+                if cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOLEAN_TEST_CODE: &str = r#"
+fun main() {
+    val a = true
+    val b = false
+    if (a || true) {
+        println(b)
+    }
+}
+"#;
+
+    struct TrueToFalseOperator;
+
+    impl MutationOperator for TrueToFalseOperator {
+        fn name(&self) -> String {
+            "TrueToFalseOperator".into()
+        }
+
+        fn matches(&self, node: &tree_sitter::Node, source: &str) -> bool {
+            node.kind() == "boolean_literal" && &source[node.byte_range()] == "true"
+        }
+
+        fn mutate(&self, _node: &tree_sitter::Node, _source: &str) -> Vec<String> {
+            vec!["false".into(), "true".into()]
+        }
+    }
+
+    #[test]
+    fn test_find_mutation_mutates_matching_nodes() {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_kotlin::language())
+            .unwrap();
+        let ast = parser.parse(BOOLEAN_TEST_CODE, None).unwrap();
+
+        let mutations = TrueToFalseOperator.find_mutation(&ast, "Main.kt", BOOLEAN_TEST_CODE);
+
+        assert_eq!(mutations.len(), 2);
+        for mutation in mutations {
+            // Replacements that do not change the node are skipped
+            assert_eq!(mutation.new_op, "false");
+            assert_eq!(mutation.old_op, "true");
+            assert_eq!(
+                &BOOLEAN_TEST_CODE[mutation.start_byte..mutation.end_byte],
+                "true"
+            );
+            assert_eq!(
+                mutation.mutation_type,
+                MutationOperators::CustomOperator("TrueToFalseOperator".into())
+            );
+        }
+    }
+}
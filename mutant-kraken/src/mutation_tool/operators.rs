@@ -1,10 +1,11 @@
-use rand::{distributions::uniform::SampleUniform, seq::SliceRandom, Rng};
+use rand::{rngs::StdRng, seq::index, Rng, SeedableRng};
 use tree_sitter::Node;
 
 use crate::{
+    config::GeneralConfig,
     error::{MutantKrakenError, Result},
     kotlin_types::{KotlinExceptions, KotlinTypes},
-    mutation_tool::Mutation,
+    mutation_tool::{is_numeric, Mutation, SymbolTable},
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -45,6 +46,48 @@ pub enum MutationOperators {
     FunctionalBinaryReplacementOperator,
     /// Changes Any() to All() or None() and vice versa or ForEach() to Map() or Filter() and vice versa
     FunctionalReplacementOperator,
+    /// Negates the condition of an if, while, or do-while or replaces it with true or false
+    ConditionNegationOperator,
+    /// Replaces the value returned from a function with an empty value of the declared return type
+    ReturnValueOperator,
+    /// Removes a call statement whose result is not used
+    StatementRemovalOperator,
+    /// Removes ?.let blocks, replaces safe calls with null, and removes ?: return or ?: throw fallbacks
+    NullSafetyOperator,
+    /// Changes isEmpty() to isNotEmpty() and vice versa, removes orEmpty(), and changes the boundary of size comparisons
+    CollectionOperator,
+    /// Changes .. to until and vice versa, shifts range bounds by one, and changes step values
+    RangeBoundaryOperator,
+    /// Empties string literals, removes string template interpolations, and swaps string functions such as trim() and trimStart()
+    StringOperator,
+    /// Changes apply() to also(), let() to run(), and takeIf() to takeUnless() and vice versa
+    ScopeFunctionOperator,
+    /// Replaces the body of an overridden equals(), hashCode(), or toString() with a call to super
+    OverrideSuperCallOperator,
+    /// Moves a property of a data class out of the primary constructor so it is no longer part of equals()
+    DataClassPropertyOperator,
+    /// Removes an init block from a class
+    InitBlockRemovalOperator,
+    /// Swaps the launch and async coroutine builders
+    CoroutineBuilderOperator,
+    /// Changes the dispatcher passed to withContext
+    DispatcherOperator,
+    /// Removes .await() and .join() calls
+    AwaitRemovalOperator,
+    /// Removes yield() and ensureActive() cancellation checks
+    CancellationCheckRemovalOperator,
+    /// Removes the statements of a catch block
+    EmptyCatchOperator,
+    /// Removes a finally block
+    FinallyRemovalOperator,
+    /// Replaces a throw expression with a no-op
+    ThrowRemovalOperator,
+    /// Changes runCatching { }.getOrThrow() to getOrNull()
+    RunCatchingOperator,
+    /// A user-defined operator loaded from a tree-sitter query file.
+    /// Serialized as the name chosen for it in the config
+    #[serde(untagged, skip_deserializing)]
+    CustomOperator(String),
 }
 
 impl Display for MutationOperators {
@@ -70,11 +113,99 @@ impl Display for MutationOperators {
                     "FunctionalBinaryReplacementOperator"
                 }
                 MutationOperators::FunctionalReplacementOperator => "FunctionalReplacementOperator",
+                MutationOperators::ConditionNegationOperator => "ConditionNegationOperator",
+                MutationOperators::ReturnValueOperator => "ReturnValueOperator",
+                MutationOperators::StatementRemovalOperator => "StatementRemovalOperator",
+                MutationOperators::NullSafetyOperator => "NullSafetyOperator",
+                MutationOperators::CollectionOperator => "CollectionOperator",
+                MutationOperators::RangeBoundaryOperator => "RangeBoundaryOperator",
+                MutationOperators::StringOperator => "StringOperator",
+                MutationOperators::ScopeFunctionOperator => "ScopeFunctionOperator",
+                MutationOperators::OverrideSuperCallOperator => "OverrideSuperCallOperator",
+                MutationOperators::DataClassPropertyOperator => "DataClassPropertyOperator",
+                MutationOperators::InitBlockRemovalOperator => "InitBlockRemovalOperator",
+                MutationOperators::CoroutineBuilderOperator => "CoroutineBuilderOperator",
+                MutationOperators::DispatcherOperator => "DispatcherOperator",
+                MutationOperators::AwaitRemovalOperator => "AwaitRemovalOperator",
+                MutationOperators::CancellationCheckRemovalOperator => {
+                    "CancellationCheckRemovalOperator"
+                }
+                MutationOperators::EmptyCatchOperator => "EmptyCatchOperator",
+                MutationOperators::FinallyRemovalOperator => "FinallyRemovalOperator",
+                MutationOperators::ThrowRemovalOperator => "ThrowRemovalOperator",
+                MutationOperators::RunCatchingOperator => "RunCatchingOperator",
+                MutationOperators::CustomOperator(name) => name.as_str(),
             }
         )
     }
 }
 
+// The values a literal can be changed to by the literal operators
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum LiteralVariant {
+    /// Changes a number to zero
+    Zero,
+    /// Changes a number to one
+    One,
+    /// Changes a number to negative one
+    MinusOne,
+    /// Adds one to a number, or changes a character to the next character
+    Increment,
+    /// Subtracts one from a number, or changes a character to the previous character
+    Decrement,
+    /// Negates a number
+    Negate,
+    /// Changes a number to the maximum value of its type
+    MaxValue,
+    /// Changes a floating point number to NaN
+    NaN,
+    /// Changes a string to the empty string
+    EmptyString,
+}
+
+// The mutants created by the WhenRemoveBranchOperator
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+pub enum WhenBranchMode {
+    /// Removes one random branch, other than the last branch, of each when
+    #[default]
+    Random,
+    /// Removes every branch of each when. The else branch is only removed when the
+    /// value of the when is not used
+    AllBranches,
+    /// Removes every branch like AllBranches, and swaps the bodies of neighbouring branches
+    AllBranchesAndSwaps,
+}
+
+// How many of the mutants an operator creates for a node are kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Cardinality {
+    /// Keeps every mutant
+    Exhaustive,
+    /// Keeps the given number of randomly picked mutants
+    Sampled(usize),
+}
+
+impl LiteralVariant {
+    /// Gets every literal variant
+    pub fn all() -> Vec<LiteralVariant> {
+        vec![
+            LiteralVariant::Zero,
+            LiteralVariant::One,
+            LiteralVariant::MinusOne,
+            LiteralVariant::Increment,
+            LiteralVariant::Decrement,
+            LiteralVariant::Negate,
+            LiteralVariant::MaxValue,
+            LiteralVariant::NaN,
+            LiteralVariant::EmptyString,
+        ]
+    }
+}
+
 impl MutationOperators {
     /// Get the operators that correspond to the mutation operator
     fn get_operators(&self) -> HashSet<KotlinTypes> {
@@ -164,9 +295,80 @@ impl MutationOperators {
                 vec![KotlinTypes::JumpExpression].into_iter().collect()
             }
             MutationOperators::FunctionalBinaryReplacementOperator
-            | MutationOperators::FunctionalReplacementOperator => {
+            | MutationOperators::FunctionalReplacementOperator
+            | MutationOperators::ScopeFunctionOperator => {
+                vec![KotlinTypes::SimpleIdentifier].into_iter().collect()
+            }
+            MutationOperators::ConditionNegationOperator => vec![
+                KotlinTypes::IfExpression,
+                KotlinTypes::WhileStatement,
+                KotlinTypes::DoWhileStatement,
+            ]
+            .into_iter()
+            .collect(),
+            MutationOperators::ReturnValueOperator => {
+                vec![KotlinTypes::FunctionBody].into_iter().collect()
+            }
+            MutationOperators::StatementRemovalOperator => {
+                vec![KotlinTypes::CallExpression].into_iter().collect()
+            }
+            MutationOperators::NullSafetyOperator => vec![
+                KotlinTypes::NonNamedType("?.".to_string()),
+                KotlinTypes::NonNamedType("?:".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+            MutationOperators::CollectionOperator => vec![
+                KotlinTypes::SimpleIdentifier,
+                KotlinTypes::NonNamedType("<".to_string()),
+                KotlinTypes::NonNamedType("<=".to_string()),
+                KotlinTypes::NonNamedType(">".to_string()),
+                KotlinTypes::NonNamedType(">=".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+            MutationOperators::RangeBoundaryOperator => {
+                vec![KotlinTypes::RangeExpression, KotlinTypes::InfixExpression]
+                    .into_iter()
+                    .collect()
+            }
+            MutationOperators::StringOperator => {
+                vec![KotlinTypes::StringLiteral, KotlinTypes::SimpleIdentifier]
+                    .into_iter()
+                    .collect()
+            }
+            MutationOperators::OverrideSuperCallOperator => {
+                vec![KotlinTypes::FunctionBody].into_iter().collect()
+            }
+            MutationOperators::DataClassPropertyOperator => {
+                vec![KotlinTypes::PrimaryConstructor].into_iter().collect()
+            }
+            MutationOperators::InitBlockRemovalOperator => vec![KotlinTypes::AnonymousInitializer]
+                .into_iter()
+                .collect(),
+            MutationOperators::CoroutineBuilderOperator | MutationOperators::DispatcherOperator => {
+                vec![KotlinTypes::SimpleIdentifier].into_iter().collect()
+            }
+            MutationOperators::AwaitRemovalOperator => {
+                vec![KotlinTypes::NavigationSuffix].into_iter().collect()
+            }
+            MutationOperators::CancellationCheckRemovalOperator => {
+                vec![KotlinTypes::CallExpression].into_iter().collect()
+            }
+            MutationOperators::EmptyCatchOperator => {
+                vec![KotlinTypes::CatchBlock].into_iter().collect()
+            }
+            MutationOperators::FinallyRemovalOperator => {
+                vec![KotlinTypes::FinallyBlock].into_iter().collect()
+            }
+            MutationOperators::ThrowRemovalOperator => {
+                vec![KotlinTypes::JumpExpression].into_iter().collect()
+            }
+            MutationOperators::RunCatchingOperator => {
                 vec![KotlinTypes::SimpleIdentifier].into_iter().collect()
             }
+            // Custom operators find their nodes with a query instead of walking the tree
+            MutationOperators::CustomOperator(_) => HashSet::new(),
         }
     }
 
@@ -218,22 +420,109 @@ impl MutationOperators {
                 vec![KotlinTypes::JumpExpression]
             }
             MutationOperators::WhenRemoveBranchOperator
-            | MutationOperators::RemoveLabelOperator => vec![KotlinTypes::AnyParent],
+            | MutationOperators::RemoveLabelOperator
+            | MutationOperators::ConditionNegationOperator
+            | MutationOperators::RangeBoundaryOperator
+            | MutationOperators::StringOperator => vec![KotlinTypes::AnyParent],
             MutationOperators::FunctionalBinaryReplacementOperator
-            | MutationOperators::FunctionalReplacementOperator => {
+            | MutationOperators::FunctionalReplacementOperator
+            | MutationOperators::ScopeFunctionOperator => {
                 vec![KotlinTypes::NavigationSuffix]
             }
+            MutationOperators::ReturnValueOperator
+            | MutationOperators::OverrideSuperCallOperator => {
+                vec![KotlinTypes::FunctionDeclaration]
+            }
+            MutationOperators::DataClassPropertyOperator => vec![KotlinTypes::ClassDeclaration],
+            MutationOperators::InitBlockRemovalOperator => vec![KotlinTypes::ClassBody],
+            MutationOperators::CoroutineBuilderOperator => {
+                vec![KotlinTypes::CallExpression, KotlinTypes::NavigationSuffix]
+            }
+            MutationOperators::DispatcherOperator => vec![KotlinTypes::NavigationSuffix],
+            MutationOperators::AwaitRemovalOperator => vec![KotlinTypes::NavigationExpression],
+            MutationOperators::CancellationCheckRemovalOperator => vec![KotlinTypes::Statements],
+            MutationOperators::EmptyCatchOperator | MutationOperators::FinallyRemovalOperator => {
+                vec![KotlinTypes::TryExpression]
+            }
+            MutationOperators::ThrowRemovalOperator => vec![KotlinTypes::AnyParent],
+            MutationOperators::RunCatchingOperator => vec![KotlinTypes::NavigationSuffix],
+            MutationOperators::StatementRemovalOperator => vec![KotlinTypes::Statements],
+            MutationOperators::NullSafetyOperator => {
+                vec![KotlinTypes::NavigationSuffix, KotlinTypes::ElvisExpression]
+            }
+            MutationOperators::CollectionOperator => vec![
+                KotlinTypes::NavigationSuffix,
+                KotlinTypes::ComparisonExpression,
+            ],
+            MutationOperators::CustomOperator(_) => vec![],
         }
     }
 
     /// Gets all the muatations that can be made to the file based on the the mutation operator
-    pub fn find_mutation(&self, ast: &tree_sitter::Tree, file_name: &String) -> Vec<Mutation> {
+    pub fn find_mutation(
+        &self,
+        ast: &tree_sitter::Tree,
+        file_name: &String,
+        config: &GeneralConfig,
+    ) -> Vec<Mutation> {
         let mut mutations = Vec::new();
         let mut cursor = ast.walk();
         let root = ast.root_node();
-        self.mutate(root, &mut cursor, None, &mut mutations, file_name);
+        // Without the source no types are known, so every mutation is kept
+        let source = fs::read_to_string(file_name).unwrap_or_default();
+        let symbols = SymbolTable::new(&root, &source);
+        self.mutate(
+            root,
+            &mut cursor,
+            None,
+            &mut mutations,
+            file_name,
+            config,
+            source.as_bytes(),
+            &symbols,
+        );
         mutations
     }
+    /// Gets the cardinality the operator uses when none is configured
+    pub fn get_default_cardinality(&self) -> Cardinality {
+        match self {
+            MutationOperators::FunctionalReplacementOperator
+            | MutationOperators::ExceptionChangeOperator => Cardinality::Sampled(1),
+            _ => Cardinality::Exhaustive,
+        }
+    }
+
+    /// Creates the random number generator used to mutate the given node.
+    ///
+    /// When a seed is configured, the generator is seeded from the seed, the operator, the contents
+    /// of the file and the position of the node. This makes the same random choices on every run,
+    /// no matter which order the files and nodes are visited in.
+    fn get_rng(
+        &self,
+        root_node: &tree_sitter::Node,
+        source: &[u8],
+        config: &GeneralConfig,
+    ) -> StdRng {
+        let seed = match config.seed {
+            Some(seed) => seed,
+            None => return StdRng::from_entropy(),
+        };
+        let operator = self.to_string();
+        let parts: [&[u8]; 4] = [
+            &seed.to_le_bytes(),
+            operator.as_bytes(),
+            source,
+            &(root_node.start_byte() as u64).to_le_bytes(),
+        ];
+        // Each part is prefixed with its length, so moving bytes between parts changes the hash
+        StdRng::seed_from_u64(fnv1a(parts.iter().flat_map(|part| {
+            (part.len() as u64)
+                .to_le_bytes()
+                .into_iter()
+                .chain(part.iter().copied())
+        })))
+    }
+
     /// Mutates the given `root` node and its children using the provided `cursor`, `parent`, `mutations_made`, `file_name`, `operators`, and `parent_necessary_types`.
     ///
     /// # Arguments
@@ -243,7 +532,11 @@ impl MutationOperators {
     /// * `parent` - An optional `tree_sitter::Node` representing the parent node of `root`.
     /// * `mutations_made` - A mutable reference to a `Vec<Mutation>` that will be populated with any mutations made during the function's execution.
     /// * `file_name` - A `String` representing the name of the file being mutated.
+    /// * `config` - The `GeneralConfig` used to configure the mutations that are made.
+    /// * `source` - The source code of the file.
+    /// * `symbols` - The `SymbolTable` of the file, used to skip mutations that do not type check.
     ///
+    #[allow(clippy::too_many_arguments)]
     fn mutate(
         &self,
         root: tree_sitter::Node,
@@ -251,18 +544,38 @@ impl MutationOperators {
         parent: Option<tree_sitter::Node>,
         mutations_made: &mut Vec<Mutation>,
         file_name: &String,
+        config: &GeneralConfig,
+        source: &[u8],
+        symbols: &SymbolTable,
     ) {
         root.children(&mut cursor.clone()).for_each(|node| {
             let root_type = KotlinTypes::new(node.kind())
-                .expect(format!("Failed to convert to KotlinType: {:?}", node.kind()).as_str());
+                .unwrap_or_else(|_| panic!("Failed to convert to KotlinType: {:?}", node.kind()));
             let parent_type = parent
                 .map(|p| KotlinTypes::new(p.kind()).expect("Failed to convert to KotlinType"));
             mutations_made.append(
                 &mut self
-                    .mutate_operator(&node, &root_type, &parent_type, file_name)
+                    .mutate_operator(
+                        &node,
+                        &root_type,
+                        &parent_type,
+                        file_name,
+                        config,
+                        source,
+                        symbols,
+                    )
                     .expect("Failed to mutate an operator"),
             );
-            self.mutate(node, cursor, Some(node), mutations_made, file_name);
+            self.mutate(
+                node,
+                cursor,
+                Some(node),
+                mutations_made,
+                file_name,
+                config,
+                source,
+                symbols,
+            );
         });
     }
 
@@ -276,16 +589,23 @@ impl MutationOperators {
     /// * `mutation_operators` - A HashSet of mutation operators to apply.
     /// * `parent_types` - A vector of parent types to check against.
     /// * `file_name` - The name of the file being mutated.
+    /// * `config` - The `GeneralConfig` used to configure the mutations that are made.
+    /// * `source` - The source code of the file, used to seed the random choices.
+    /// * `symbols` - The `SymbolTable` of the file, used to skip mutations that do not type check.
     ///
     /// # Returns
     ///
     /// A Result containing a vector of mutations made.
+    #[allow(clippy::too_many_arguments)]
     fn mutate_operator(
         &self,
         root_node: &tree_sitter::Node,
         root: &KotlinTypes,
         parent: &Option<KotlinTypes>,
         file_name: &str,
+        config: &GeneralConfig,
+        source: &[u8],
+        symbols: &SymbolTable,
     ) -> Result<Vec<Mutation>> {
         let mut mutations_made = Vec::new();
         let mutation_operators = self.get_operators();
@@ -333,14 +653,24 @@ impl MutationOperators {
                 mutations_made.push(mutation);
             }
             MutationOperators::ElvisLiteralChangeOperator
-            | MutationOperators::LiteralChangeOperator => {
-                self.mutate_literal(&root_node.parent().unwrap(), &mut mutations_made, file_name)
-            }
+            | MutationOperators::LiteralChangeOperator => self.mutate_literal(
+                &root_node.parent().unwrap(),
+                &mut mutations_made,
+                file_name,
+                &config.get_literal_variants(self),
+            ),
             MutationOperators::ExceptionChangeOperator => {
                 self.mutate_exception(root_node, &mut mutations_made, file_name)
             }
             MutationOperators::WhenRemoveBranchOperator => {
-                self.mutate_when(root_node, &mut mutations_made, file_name)
+                let mut rng = self.get_rng(root_node, source, config);
+                self.mutate_when(
+                    root_node,
+                    &mut mutations_made,
+                    file_name,
+                    &mut rng,
+                    &config.when_branch_mode,
+                )
             }
             MutationOperators::RemoveLabelOperator => {
                 self.mutate_label(root_node, &mut mutations_made, file_name)
@@ -351,10 +681,67 @@ impl MutationOperators {
             MutationOperators::FunctionalReplacementOperator => {
                 self.mutate_functional(root_node, &mut mutations_made, file_name)
             }
+            MutationOperators::ConditionNegationOperator => {
+                self.mutate_condition(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::ReturnValueOperator => {
+                self.mutate_return_value(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::StatementRemovalOperator => {
+                self.mutate_statement(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::NullSafetyOperator => {
+                self.mutate_null_safety(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::CollectionOperator => {
+                self.mutate_collection(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::RangeBoundaryOperator => {
+                self.mutate_range(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::StringOperator => {
+                self.mutate_string(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::ScopeFunctionOperator => {
+                self.mutate_scope_function(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::OverrideSuperCallOperator => {
+                self.mutate_override(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::DataClassPropertyOperator => {
+                self.mutate_data_class(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::InitBlockRemovalOperator => {
+                self.mutate_init_block(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::CoroutineBuilderOperator => {
+                self.mutate_coroutine_builder(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::DispatcherOperator => {
+                self.mutate_dispatcher(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::AwaitRemovalOperator => {
+                self.mutate_await(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::CancellationCheckRemovalOperator => {
+                self.mutate_cancellation_check(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::EmptyCatchOperator => {
+                self.mutate_catch(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::FinallyRemovalOperator => {
+                self.mutate_finally(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::ThrowRemovalOperator => {
+                self.mutate_throw(root_node, &mut mutations_made, file_name)
+            }
+            MutationOperators::RunCatchingOperator => {
+                self.mutate_run_catching(root_node, &mut mutations_made, file_name)
+            }
             _ => {
                 // Create a mutant for all mutation operators
                 mutation_operators.iter().for_each(|operator| {
-                    if operator != root {
+                    if operator != root && self.type_checks(root_node, operator, symbols) {
                         let mutation = Mutation::new(
                             root_node.start_byte(),
                             root_node.end_byte(),
@@ -370,87 +757,180 @@ impl MutationOperators {
             }
         }
 
+        // Only keep the configured number of mutants for the node
+        if let Cardinality::Sampled(count) = config.get_cardinality(self) {
+            if mutations_made.len() > count {
+                // Sort first so that the sample only depends on the seed
+                mutations_made
+                    .sort_by(|a, b| (a.start_byte, &a.new_op).cmp(&(b.start_byte, &b.new_op)));
+                let mut rng = self.get_rng(root_node, source, config);
+                let mut indices = index::sample(&mut rng, mutations_made.len(), count).into_vec();
+                indices.sort();
+                mutations_made = indices
+                    .into_iter()
+                    .map(|i| mutations_made[i].clone())
+                    .collect();
+            }
+        }
+
         Ok(mutations_made)
     }
 
-    fn mutate_functional(
+    /// Checks if replacing the operator `root_node` with `new_op` still type checks, based on the
+    /// types in the `symbols` of the file. Operands with an unknown type are assumed to type check
+    fn type_checks(&self, root_node: &Node, new_op: &KotlinTypes, symbols: &SymbolTable) -> bool {
+        let parent = match root_node.parent() {
+            Some(parent) => parent,
+            None => return true,
+        };
+        let new_op = new_op.to_string();
+        match self {
+            MutationOperators::ArithmeticReplacementOperator => {
+                // Strings can only be added to
+                if symbols.expression_type(&parent).as_deref() == Some("String") {
+                    return false;
+                }
+                // Chars can only be moved up and down
+                let left = parent
+                    .named_child(0)
+                    .and_then(|n| symbols.expression_type(&n));
+                left.as_deref() != Some("Char") || new_op == "+" || new_op == "-"
+            }
+            MutationOperators::AssignmentReplacementOperator => {
+                let target = parent
+                    .named_child(0)
+                    .and_then(|n| n.named_child(0))
+                    .and_then(|n| symbols.expression_type(&n));
+                match target.as_deref() {
+                    Some("String") => new_op == "=" || new_op == "+=",
+                    Some("Boolean") => new_op == "=",
+                    Some("Char") => new_op == "=" || new_op == "+=" || new_op == "-=",
+                    _ => true,
+                }
+            }
+            MutationOperators::UnaryReplacementOperator => {
+                let operand = parent
+                    .named_children(&mut parent.walk())
+                    .find(|n| n.id() != root_node.id())
+                    .and_then(|n| symbols.expression_type(&n));
+                match operand.as_deref() {
+                    Some("Boolean") => new_op != "++" && new_op != "--",
+                    Some(operand) if is_numeric(operand) => new_op != "!",
+                    _ => true,
+                }
+            }
+            _ => true,
+        }
+    }
+
+    fn mutate_condition(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
     ) {
-        let predicates = ["any", "all", "none"];
-        let transform = ["forEach", "map", "filter"];
+        // The condition is the node directly after the opening parenthesis
+        let condition = match root_node
+            .children(&mut root_node.walk())
+            .skip_while(|node| node.kind() != "(")
+            .nth(1)
+        {
+            Some(node) => node,
+            None => return,
+        };
 
-        // Need to make sure the current node is either "any", "all", "none", "forEach", "map", or "filter"
-        // If it is, then we need to change it to the other one
         let file = fs::read(file_name).expect("Failed to read file");
         let file = file.as_slice();
-        let val = root_node.utf8_text(file).unwrap();
+        let val = condition.utf8_text(file).unwrap();
+
+        // Negate the whole condition and replace it with both boolean literals
+        let mut_vals = [format!("!({})", val), "true".into(), "false".into()];
+        for mut_val in mut_vals.into_iter().filter(|mut_val| mut_val != val) {
+            let mutation = Mutation::new(
+                condition.start_byte(),
+                condition.end_byte(),
+                mut_val,
+                val.to_string(),
+                condition.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
 
-        if !predicates.contains(&val) && !transform.contains(&val) {
+    fn mutate_return_value(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // The return type is the node directly after the colon of the function declaration
+        let function_declaration = root_node.parent().unwrap();
+        let return_type = match function_declaration
+            .children(&mut function_declaration.walk())
+            .skip_while(|node| node.kind() != ":")
+            .nth(1)
+        {
+            Some(node) => node,
+            None => return,
+        };
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let empty_values = get_empty_return_values(&return_type, file);
+        if empty_values.is_empty() {
             return;
         }
 
-        // Pick a random method that is not the same as the original method
-        let mut rng = rand::thread_rng();
-        let mut mut_val = val;
-        let type_val = if predicates.contains(&val) {
-            "predicate"
+        // An expression body returns the node after the equals sign, otherwise
+        // we need to find every return statement in the body
+        let mut returned_expressions = Vec::new();
+        let first_child = root_node.child(0).unwrap();
+        if first_child.kind() == "=" {
+            returned_expressions.extend(first_child.next_sibling());
         } else {
-            "transform"
-        };
-        while mut_val == val {
-            mut_val = match type_val {
-                "predicate" => predicates.choose(&mut rng).unwrap(),
-                "transform" => transform.choose(&mut rng).unwrap(),
-                _ => panic!("Invalid type"),
-            };
+            collect_returned_expressions(*root_node, &mut returned_expressions);
         }
 
-        let mutation = Mutation::new(
-            root_node.start_byte(),
-            root_node.end_byte(),
-            mut_val.into(),
-            val.to_string(),
-            root_node.start_position().row + 1,
-            self.clone(),
-            file_name.to_string(),
-        );
-        mutations_made.push(mutation);
+        for expression in returned_expressions {
+            let val = expression.utf8_text(file).unwrap();
+            for mut_val in empty_values.iter().filter(|mut_val| *mut_val != val) {
+                let mutation = Mutation::new(
+                    expression.start_byte(),
+                    expression.end_byte(),
+                    mut_val.clone(),
+                    val.to_string(),
+                    expression.start_position().row + 1,
+                    self.clone(),
+                    file_name.to_string(),
+                );
+                mutations_made.push(mutation);
+            }
+        }
     }
 
-    fn mutate_functional_binary(
+    fn mutate_statement(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
     ) {
-        let changes = HashMap::from([
-            ("first", "last"),
-            ("last", "first"),
-            ("firstOrNull", "lastOrNull"),
-            ("lastOrNull", "firstOrNull"),
-            ("find", "findLast"),
-            ("findLast", "find"),
-        ]);
+        // The value of the call is used when it is the last statement of a lambda, or of a
+        // branch or try used as a value
+        if !is_statement(root_node) {
+            return;
+        }
 
-        // Need to make sure the current node is either "first", "last", "firstOrNull", or "lastOrNull"
-        // If it is, then we need to change it to the other one
         let file = fs::read(file_name).expect("Failed to read file");
         let file = file.as_slice();
         let val = root_node.utf8_text(file).unwrap();
 
-        if !changes.contains_key(val) {
-            return;
-        }
-
-        let mut_val = changes[val];
-
+        // Remove the call statement
         let mutation = Mutation::new(
             root_node.start_byte(),
             root_node.end_byte(),
-            mut_val.into(),
+            KotlinTypes::RemoveOperator.to_string(),
             val.to_string(),
             root_node.start_position().row + 1,
             self.clone(),
@@ -459,125 +939,929 @@ impl MutationOperators {
         mutations_made.push(mutation);
     }
 
-    fn mutate_label(
+    fn mutate_null_safety(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
     ) {
-        // Get the value of the node
         let file = fs::read(file_name).expect("Failed to read file");
         let file = file.as_slice();
-        let val = root_node.utf8_text(file).unwrap();
 
-        // If the value is not a label, return
-        if !val.contains('@')
-            && (!val.starts_with("return")
-                || !val.starts_with("continue")
-                || !val.starts_with("break"))
-        {
+        if root_node.kind() == "?:" {
+            // Only remove fallbacks that return or throw
+            let elvis_expression = root_node.parent().unwrap();
+            let fallback = match root_node.next_sibling() {
+                Some(node) if node.kind() == KotlinTypes::JumpExpression.as_str() => node,
+                _ => return,
+            };
+            let jump = fallback.utf8_text(file).unwrap();
+            if !jump.starts_with("return") && !jump.starts_with("throw") {
+                return;
+            }
+
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                elvis_expression.end_byte(),
+                KotlinTypes::RemoveOperator.to_string(),
+                format!("?: {}", jump),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
             return;
         }
 
-        // Remove the label
-        let label_start = val.find('@').unwrap();
-        let mut_value = val[0..label_start].to_string();
-
-        let mutation = Mutation::new(
-            root_node.start_byte(),
-            root_node.end_byte(),
-            mut_value,
-            val.to_string(),
-            root_node.start_position().row + 1,
-            self.clone(),
-            file_name.to_string(),
-        );
-
+        // The safe call is either a property access or the function of a call expression
+        let navigation_suffix = root_node.parent().unwrap();
+        let navigation_expression = navigation_suffix.parent().unwrap();
+        let call_expression = navigation_expression
+            .parent()
+            .filter(|p| p.kind() == KotlinTypes::CallExpression.as_str());
+        let identifier = navigation_suffix
+            .child(1)
+            .and_then(|n| n.utf8_text(file).ok())
+            .unwrap_or_default();
+
+        let mutation = match call_expression {
+            Some(call_expression) if identifier == "let" => {
+                // Remove the ?.let block, leaving only the receiver
+                Mutation::new(
+                    navigation_suffix.start_byte(),
+                    call_expression.end_byte(),
+                    KotlinTypes::RemoveOperator.to_string(),
+                    call_expression.utf8_text(file).unwrap()
+                        [navigation_suffix.start_byte() - call_expression.start_byte()..]
+                        .to_string(),
+                    root_node.start_position().row + 1,
+                    self.clone(),
+                    file_name.to_string(),
+                )
+            }
+            _ => {
+                // Replace the safe call with null
+                let node = call_expression.unwrap_or(navigation_expression);
+                Mutation::new(
+                    node.start_byte(),
+                    node.end_byte(),
+                    "null".into(),
+                    node.utf8_text(file).unwrap().to_string(),
+                    node.start_position().row + 1,
+                    self.clone(),
+                    file_name.to_string(),
+                )
+            }
+        };
         mutations_made.push(mutation);
     }
 
-    fn mutate_when(
+    fn mutate_collection(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
     ) {
-        // Get the when entry list
-        let when_entry_list = root_node
-            .children(&mut root_node.walk())
-            .filter_map(|node| {
-                let kt_node =
-                    KotlinTypes::new(node.kind()).expect("Failed to convert to KotlinType");
-                if kt_node == KotlinTypes::WhenEntry {
-                    Some(node)
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<Node<'_>>>();
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
 
-        // If the when entry list has more than one entry, remove one of the entries
-        if when_entry_list.len() < 2 {
+        // Change the boundary of comparisons against the size of a collection
+        let boundaries = HashMap::from([(">", ">="), (">=", ">"), ("<", "<="), ("<=", "<")]);
+        if boundaries.contains_key(val) {
+            let is_size = |node: Option<Node>| {
+                node.and_then(|n| n.utf8_text(file).ok())
+                    .is_some_and(|text| text == "size" || text.ends_with(".size"))
+            };
+            if !is_size(root_node.prev_sibling()) && !is_size(root_node.next_sibling()) {
+                return;
+            }
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                root_node.end_byte(),
+                boundaries[val].into(),
+                val.to_string(),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
             return;
         }
 
-        // Get a random branch other than the last branch (this is usually the else branch)
-        let mut rng = rand::thread_rng();
-        let index = rng.gen_range(0..when_entry_list.len() - 1);
-        let node = when_entry_list.get(index).unwrap();
+        // Need to make sure the current node is one of the stdlib emptiness helpers
+        // If it is, then we need to change it to the other one
+        let changes = HashMap::from([
+            ("isEmpty", "isNotEmpty"),
+            ("isNotEmpty", "isEmpty"),
+            ("isNullOrEmpty", "isNotEmpty"),
+        ]);
+        if changes.contains_key(val) {
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                root_node.end_byte(),
+                changes[val].into(),
+                val.to_string(),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+            return;
+        }
 
-        // Remove the node
+        // Remove the orEmpty() call, leaving only the receiver
+        if val != "orEmpty" {
+            return;
+        }
+        let navigation_suffix = root_node.parent().unwrap();
+        let call_expression = match navigation_suffix
+            .parent()
+            .and_then(|p| p.parent())
+            .filter(|p| p.kind() == KotlinTypes::CallExpression.as_str())
+        {
+            Some(node) => node,
+            None => return,
+        };
         let mutation = Mutation::new(
-            node.start_byte(),
-            node.end_byte(),
+            navigation_suffix.start_byte(),
+            call_expression.end_byte(),
             KotlinTypes::RemoveOperator.to_string(),
-            "When Branch".to_string(),
-            node.start_position().row + 1,
+            call_expression.utf8_text(file).unwrap()
+                [navigation_suffix.start_byte() - call_expression.start_byte()..]
+                .to_string(),
+            root_node.start_position().row + 1,
             self.clone(),
             file_name.to_string(),
         );
-
         mutations_made.push(mutation);
     }
 
-    fn mutate_exception(
+    fn mutate_range(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
     ) {
-        // Get the sibling of the root node
-        let sibling = root_node.next_sibling().unwrap();
-        let sibling_type =
-            KotlinTypes::new(sibling.kind()).expect("Failed to convert to KotlinType");
-        // If the sibling is a call expression, i want to grab the simple identifier (its child)
-        if sibling_type != KotlinTypes::CallExpression {
+        // Ranges are made up of a start, an operator, and an end
+        if root_node.child_count() != 3 {
             return;
         }
+        let start = root_node.child(0).unwrap();
+        let operator = root_node.child(1).unwrap();
+        let end = root_node.child(2).unwrap();
 
-        let child = sibling.children(&mut sibling.walk()).next().unwrap();
-        let child_type = KotlinTypes::new(child.kind()).expect("Failed to convert to KotlinType");
-        // Check to see if the child is a simple identifier
-        if child_type != KotlinTypes::SimpleIdentifier {
-            return;
-        }
-        // Get the value
         let file = fs::read(file_name).expect("Failed to read file");
         let file = file.as_slice();
-        let val = child.utf8_text(file).unwrap();
-        let exception: KotlinExceptions = val
-            .parse()
-            .unwrap_or(KotlinExceptions::ArithmArithmeticException);
+        let op = operator.utf8_text(file).unwrap();
+
+        let mut changes = vec![];
+        match op {
+            // The grammar does not support ..< yet, so until is used for open ranges
+            ".." => changes.push((operator, " until ".to_string())),
+            "until" => changes.push((operator, "..".to_string())),
+            "downTo" => {}
+            "step" => {
+                for mut_val in shift_by_one(end.utf8_text(file).unwrap()) {
+                    changes.push((end, mut_val));
+                }
+            }
+            _ => return,
+        }
+        if op != "step" {
+            // Shift both bounds of the range by one
+            for bound in [start, end] {
+                for mut_val in shift_by_one(bound.utf8_text(file).unwrap()) {
+                    changes.push((bound, mut_val));
+                }
+            }
+        }
+
+        for (node, mut_val) in changes {
+            let mutation = Mutation::new(
+                node.start_byte(),
+                node.end_byte(),
+                mut_val,
+                node.utf8_text(file).unwrap().to_string(),
+                node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+
+    fn mutate_string(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let changes = HashMap::from([
+            ("trim", "trimStart"),
+            ("trimStart", "trim"),
+            ("trimEnd", "trim"),
+            ("uppercase", "lowercase"),
+            ("lowercase", "uppercase"),
+            ("startsWith", "endsWith"),
+            ("endsWith", "startsWith"),
+        ]);
+
+        // String functions are only swapped when they are called on a receiver
+        let is_identifier = root_node.kind() == KotlinTypes::SimpleIdentifier.as_str();
+        let is_called_on_receiver = root_node
+            .parent()
+            .is_some_and(|p| p.kind() == KotlinTypes::NavigationSuffix.as_str());
+        if is_identifier && !is_called_on_receiver {
+            return;
+        }
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if is_identifier {
+            if !changes.contains_key(val) {
+                return;
+            }
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                root_node.end_byte(),
+                changes[val].into(),
+                val.to_string(),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+            return;
+        }
+
+        // Empty the string literal
+        if val != r#""""# {
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                root_node.end_byte(),
+                r#""""#.into(),
+                val.to_string(),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+
+        // Remove each interpolation from the template, an interpolation is either
+        // ${expression} or $identifier
+        let children = root_node
+            .children(&mut root_node.walk())
+            .collect::<Vec<Node>>();
+        for (i, child) in children.iter().enumerate() {
+            let end = match (child.kind(), children.get(i + 1), children.get(i + 2)) {
+                ("$", Some(identifier), _) => identifier.end_byte(),
+                ("${", _, Some(close)) => close.end_byte(),
+                _ => continue,
+            };
+            let mutation = Mutation::new(
+                child.start_byte(),
+                end,
+                KotlinTypes::RemoveOperator.to_string(),
+                String::from_utf8_lossy(&file[child.start_byte()..end]).to_string(),
+                child.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+
+    fn mutate_override(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let function_declaration = root_node.parent().unwrap();
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        // Only overridden functions can call super
+        let is_override = function_declaration
+            .children(&mut function_declaration.walk())
+            .find(|node| node.kind() == KotlinTypes::Modifiers.as_str())
+            .and_then(|node| node.utf8_text(file).ok())
+            .is_some_and(|modifiers| modifiers.split_whitespace().any(|m| m == "override"));
+        if !is_override {
+            return;
+        }
+
+        let name = function_declaration
+            .children(&mut function_declaration.walk())
+            .find(|node| node.kind() == KotlinTypes::SimpleIdentifier.as_str())
+            .and_then(|node| node.utf8_text(file).ok())
+            .unwrap_or_default();
+        let super_call = match name {
+            "hashCode" | "toString" => format!("= super.{}()", name),
+            "equals" => {
+                // Pass the parameter of equals through to super
+                let parameter = function_declaration
+                    .children(&mut function_declaration.walk())
+                    .find(|node| node.kind() == "function_value_parameters")
+                    .and_then(|node| {
+                        node.children(&mut node.walk())
+                            .find(|n| n.kind() == KotlinTypes::Parameter.as_str())
+                    })
+                    .and_then(|node| node.child(0))
+                    .and_then(|node| node.utf8_text(file).ok());
+                match parameter {
+                    Some(parameter) => format!("= super.equals({})", parameter),
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            super_call,
+            root_node.utf8_text(file).unwrap().to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_data_class(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let class_declaration = root_node.parent().unwrap();
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        let is_data_class = class_declaration
+            .children(&mut class_declaration.walk())
+            .find(|node| node.kind() == KotlinTypes::Modifiers.as_str())
+            .and_then(|node| node.utf8_text(file).ok())
+            .is_some_and(|modifiers| modifiers.split_whitespace().any(|m| m == "data"));
+        if !is_data_class {
+            return;
+        }
+
+        // Only parameters that start with val or var are properties
+        let properties = root_node
+            .children(&mut root_node.walk())
+            .filter(|node| node.kind() == KotlinTypes::ClassParameter.as_str())
+            .filter(|node| {
+                node.child(0)
+                    .is_some_and(|n| n.kind() == "val" || n.kind() == "var")
+            })
+            .collect::<Vec<Node>>();
+        // A data class needs at least one property in its primary constructor
+        if properties.len() < 2 {
+            return;
+        }
+
+        let class_body = class_declaration
+            .children(&mut class_declaration.walk())
+            .find(|node| node.kind() == KotlinTypes::ClassBody.as_str());
+        let text =
+            |start: usize, end: usize| String::from_utf8_lossy(&file[start..end]).to_string();
+
+        for property in properties {
+            let binding = property.child(0).unwrap();
+            let name = property.child(1).unwrap();
+            let property_type = match property
+                .children(&mut property.walk())
+                .skip_while(|node| node.kind() != ":")
+                .nth(1)
+            {
+                Some(node) => node,
+                None => continue,
+            };
+            let name = name.utf8_text(file).unwrap();
+            // Keep the constructor parameter and initialize a body property with it
+            let body_property = format!(
+                "{} {}: {} = {}",
+                binding.utf8_text(file).unwrap(),
+                name,
+                property_type.utf8_text(file).unwrap(),
+                name
+            );
+            let parameter = text(binding.end_byte(), property.end_byte());
+            let parameter = parameter.trim_start();
+
+            let (end_byte, mut_val) = match class_body.and_then(|body| body.child(0)) {
+                Some(open_brace) => (
+                    open_brace.end_byte(),
+                    format!(
+                        "{}{}\n    {}",
+                        parameter,
+                        text(property.end_byte(), open_brace.end_byte()),
+                        body_property
+                    ),
+                ),
+                None => (
+                    class_declaration.end_byte(),
+                    format!(
+                        "{}{} {{\n    {}\n}}",
+                        parameter,
+                        text(property.end_byte(), class_declaration.end_byte()),
+                        body_property
+                    ),
+                ),
+            };
+
+            let mutation = Mutation::new(
+                property.start_byte(),
+                end_byte,
+                mut_val,
+                text(property.start_byte(), end_byte),
+                property.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+
+    fn mutate_init_block(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        // Remove the init block
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            KotlinTypes::RemoveOperator.to_string(),
+            root_node.utf8_text(file).unwrap().to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_functional(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let predicates = ["any", "all", "none"];
+        let transform = ["forEach", "map", "filter"];
+
+        // Need to make sure the current node is either "any", "all", "none", "forEach", "map", or "filter"
+        // If it is, then we need to change it to the other one
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if !predicates.contains(&val) && !transform.contains(&val) {
+            return;
+        }
+
+        // Create a mutant for every other method of the same kind, the cardinality of the
+        // operator decides how many of them are kept
+        let methods = if predicates.contains(&val) {
+            predicates
+        } else {
+            transform
+        };
+        for mut_val in methods.iter().filter(|method| **method != val) {
+            let mutation = Mutation::new(
+                root_node.start_byte(),
+                root_node.end_byte(),
+                mut_val.to_string(),
+                val.to_string(),
+                root_node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+
+    fn mutate_functional_binary(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let changes = HashMap::from([
+            ("first", "last"),
+            ("last", "first"),
+            ("firstOrNull", "lastOrNull"),
+            ("lastOrNull", "firstOrNull"),
+            ("find", "findLast"),
+            ("findLast", "find"),
+        ]);
+
+        // Need to make sure the current node is either "first", "last", "firstOrNull", or "lastOrNull"
+        // If it is, then we need to change it to the other one
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if !changes.contains_key(val) {
+            return;
+        }
+
+        let mut_val = changes[val];
+
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            mut_val.into(),
+            val.to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_scope_function(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // Only swap scope functions that return the same thing. apply and also return the
+        // receiver, let and run return the lambda result
+        let changes = HashMap::from([
+            ("apply", "also"),
+            ("also", "apply"),
+            ("let", "run"),
+            ("run", "let"),
+            ("takeIf", "takeUnless"),
+            ("takeUnless", "takeIf"),
+        ]);
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if !changes.contains_key(val) {
+            return;
+        }
+
+        // The lambda of apply and run gets the receiver as this, the lambda of also and let gets
+        // it as it, so the swap only compiles when the lambda uses neither
+        if scope_function_lambda_uses_receiver(root_node, val, file) {
+            return;
+        }
+
+        let mut_val = changes[val];
+
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            mut_val.into(),
+            val.to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_coroutine_builder(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let changes = HashMap::from([("launch", "async"), ("async", "launch")]);
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if !changes.contains_key(val) {
+            return;
+        }
+
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            changes[val].into(),
+            val.to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_dispatcher(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let dispatchers = ["IO", "Default", "Main"];
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if !dispatchers.contains(&val) {
+            return;
+        }
+
+        // Make sure this is Dispatchers.X
+        let navigation_expression = root_node.parent().unwrap().parent().unwrap();
+        if navigation_expression
+            .child(0)
+            .and_then(|n| n.utf8_text(file).ok())
+            != Some("Dispatchers")
+        {
+            return;
+        }
+
+        // Make sure the dispatcher is an argument of withContext
+        // value_argument -> value_arguments -> call_suffix -> call_expression
+        let call_expression = (0..4).try_fold(navigation_expression, |node, _| node.parent());
+        let is_with_context = call_expression
+            .and_then(|n| n.child(0))
+            .and_then(|n| n.utf8_text(file).ok())
+            == Some("withContext");
+        if !is_with_context {
+            return;
+        }
+
+        dispatchers
+            .iter()
+            .filter(|dispatcher| **dispatcher != val)
+            .for_each(|dispatcher| {
+                let mutation = Mutation::new(
+                    root_node.start_byte(),
+                    root_node.end_byte(),
+                    dispatcher.to_string(),
+                    val.to_string(),
+                    root_node.start_position().row + 1,
+                    self.clone(),
+                    file_name.to_string(),
+                );
+                mutations_made.push(mutation);
+            });
+    }
+
+    fn mutate_await(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        let name = root_node
+            .named_child(0)
+            .and_then(|n| n.utf8_text(file).ok())
+            .unwrap_or_default();
+        if name != "await" && name != "join" {
+            return;
+        }
+
+        // The call must not take any arguments
+        let navigation_expression = root_node.parent().unwrap();
+        let call_expression = match navigation_expression.parent() {
+            Some(node) if node.kind() == KotlinTypes::CallExpression.as_str() => node,
+            _ => return,
+        };
+        let is_called_without_arguments = call_expression
+            .child(1)
+            .and_then(|n| n.utf8_text(file).ok())
+            .is_some_and(|call_suffix| call_suffix.replace(' ', "") == "()");
+        if !is_called_without_arguments {
+            return;
+        }
+
+        // Remove the call, keeping the receiver
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            call_expression.end_byte(),
+            KotlinTypes::RemoveOperator.to_string(),
+            String::from_utf8_lossy(&file[root_node.start_byte()..call_expression.end_byte()])
+                .to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_cancellation_check(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        let callee = root_node
+            .child(0)
+            .and_then(|n| n.utf8_text(file).ok())
+            .unwrap_or_default();
+        let is_cancellation_check =
+            callee == "yield" || callee == "ensureActive" || callee.ends_with(".ensureActive");
+        let is_called_without_arguments = root_node
+            .child(1)
+            .and_then(|n| n.utf8_text(file).ok())
+            .is_some_and(|call_suffix| call_suffix.replace(' ', "") == "()");
+        if !is_cancellation_check || !is_called_without_arguments {
+            return;
+        }
+
+        // Remove the cancellation check
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            KotlinTypes::RemoveOperator.to_string(),
+            val.to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_catch(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // An empty catch block is already swallowing the exception
+        let statements = match root_node
+            .children(&mut root_node.walk())
+            .find(|node| node.kind() == KotlinTypes::Statements.as_str())
+        {
+            Some(node) => node,
+            None => return,
+        };
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        // Remove the statements, swallowing the exception
+        let mutation = Mutation::new(
+            statements.start_byte(),
+            statements.end_byte(),
+            KotlinTypes::RemoveOperator.to_string(),
+            statements.utf8_text(file).unwrap().to_string(),
+            statements.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_finally(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // A try needs either a catch or a finally block
+        let try_expression = root_node.parent().unwrap();
+        let has_catch_block = try_expression
+            .children(&mut try_expression.walk())
+            .any(|node| node.kind() == KotlinTypes::CatchBlock.as_str());
+        if !has_catch_block {
+            return;
+        }
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        // Remove the finally block
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            KotlinTypes::RemoveOperator.to_string(),
+            root_node.utf8_text(file).unwrap().to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_throw(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        if root_node.child(0).is_none_or(|node| node.kind() != "throw") {
+            return;
+        }
+        // A throw used as a value has the type Nothing, which Unit can not replace. Removing the
+        // fallback of an elvis expression is handled by the NullSafetyOperator
+        if !is_statement(root_node) {
+            return;
+        }
+
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+
+        // Replace the throw with a no-op
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            "Unit".into(),
+            root_node.utf8_text(file).unwrap().to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_run_catching(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        if val != "getOrThrow" {
+            return;
+        }
+
+        // Make sure the receiver is a runCatching call
+        // navigation_suffix -> navigation_expression -> call_expression -> callee
+        let is_run_catching = root_node
+            .parent()
+            .and_then(|n| n.parent())
+            .and_then(|n| n.child(0))
+            .filter(|n| n.kind() == KotlinTypes::CallExpression.as_str())
+            .and_then(|n| n.child(0))
+            .and_then(|n| n.utf8_text(file).ok())
+            .is_some_and(|callee| callee == "runCatching" || callee.ends_with(".runCatching"));
+        if !is_run_catching {
+            return;
+        }
+
+        let mutation = Mutation::new(
+            root_node.start_byte(),
+            root_node.end_byte(),
+            "getOrNull".into(),
+            val.to_string(),
+            root_node.start_position().row + 1,
+            self.clone(),
+            file_name.to_string(),
+        );
+        mutations_made.push(mutation);
+    }
+
+    fn mutate_label(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // Get the value of the node
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = root_node.utf8_text(file).unwrap();
+
+        // If the value is not a label, return
+        if !val.contains('@')
+            && (!val.starts_with("return")
+                || !val.starts_with("continue")
+                || !val.starts_with("break"))
+        {
+            return;
+        }
 
-        // Pick a random exception that is not the same as the original exception
-        let mut_val = exception.get_random_exception().to_string();
+        // Remove the label
+        let label_start = val.find('@').unwrap();
+        let mut_value = val[0..label_start].to_string();
 
         let mutation = Mutation::new(
-            child.start_byte(),
-            child.end_byte(),
-            mut_val,
+            root_node.start_byte(),
+            root_node.end_byte(),
+            mut_value,
             val.to_string(),
-            child.start_position().row + 1,
+            root_node.start_position().row + 1,
             self.clone(),
             file_name.to_string(),
         );
@@ -585,11 +1869,161 @@ impl MutationOperators {
         mutations_made.push(mutation);
     }
 
+    fn mutate_when(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+        rng: &mut StdRng,
+        mode: &WhenBranchMode,
+    ) {
+        // Get the when entry list
+        let when_entry_list = root_node
+            .children(&mut root_node.walk())
+            .filter(|node| {
+                let kt_node =
+                    KotlinTypes::new(node.kind()).expect("Failed to convert to KotlinType");
+                kt_node == KotlinTypes::WhenEntry
+            })
+            .collect::<Vec<Node<'_>>>();
+
+        // If the when entry list has more than one entry, remove one of the entries
+        if when_entry_list.len() < 2 {
+            return;
+        }
+
+        let removed_entries = match mode {
+            WhenBranchMode::Random => {
+                // Get a random branch other than the last branch (this is usually the else branch)
+                let index = rng.gen_range(0..when_entry_list.len() - 1);
+                vec![*when_entry_list.get(index).unwrap()]
+            }
+            WhenBranchMode::AllBranches | WhenBranchMode::AllBranchesAndSwaps => {
+                // The else branch can only be removed when the value of the when is not used,
+                // otherwise the when may no longer be exhaustive
+                let is_statement = root_node.parent().is_some_and(|parent| {
+                    parent.kind() == KotlinTypes::Statements.as_str()
+                        && (root_node.next_named_sibling().is_some()
+                            || parent
+                                .parent()
+                                .is_some_and(|p| p.kind() != KotlinTypes::LambdaLiteral.as_str()))
+                });
+                when_entry_list
+                    .iter()
+                    .filter(|entry| {
+                        is_statement || entry.child(0).is_none_or(|n| n.kind() != "else")
+                    })
+                    .copied()
+                    .collect()
+            }
+        };
+
+        for node in removed_entries {
+            // Remove the node
+            let mutation = Mutation::new(
+                node.start_byte(),
+                node.end_byte(),
+                KotlinTypes::RemoveOperator.to_string(),
+                "When Branch".to_string(),
+                node.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+
+            mutations_made.push(mutation);
+        }
+
+        if *mode != WhenBranchMode::AllBranchesAndSwaps {
+            return;
+        }
+
+        // Swap the bodies of each pair of neighbouring branches
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let bodies = when_entry_list
+            .iter()
+            .filter_map(|entry| {
+                entry
+                    .children(&mut entry.walk())
+                    .find(|n| n.kind() == "control_structure_body")
+            })
+            .collect::<Vec<Node>>();
+        for pair in bodies.windows(2) {
+            let (first, second) = (pair[0], pair[1]);
+            let first_text = first.utf8_text(file).unwrap();
+            let second_text = second.utf8_text(file).unwrap();
+            if first_text == second_text {
+                continue;
+            }
+            let between = String::from_utf8_lossy(&file[first.end_byte()..second.start_byte()]);
+            let mutation = Mutation::new(
+                first.start_byte(),
+                second.end_byte(),
+                format!("{}{}{}", second_text, between, first_text),
+                String::from_utf8_lossy(&file[first.start_byte()..second.end_byte()]).to_string(),
+                first.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+
+    fn mutate_exception(
+        &self,
+        root_node: &tree_sitter::Node,
+        mutations_made: &mut Vec<Mutation>,
+        file_name: &str,
+    ) {
+        // Get the sibling of the root node
+        let sibling = root_node.next_sibling().unwrap();
+        let sibling_type =
+            KotlinTypes::new(sibling.kind()).expect("Failed to convert to KotlinType");
+        // If the sibling is a call expression, i want to grab the simple identifier (its child)
+        if sibling_type != KotlinTypes::CallExpression {
+            return;
+        }
+
+        let child = sibling.children(&mut sibling.walk()).next().unwrap();
+        let child_type = KotlinTypes::new(child.kind()).expect("Failed to convert to KotlinType");
+        // Check to see if the child is a simple identifier
+        if child_type != KotlinTypes::SimpleIdentifier {
+            return;
+        }
+        // Get the value
+        let file = fs::read(file_name).expect("Failed to read file");
+        let file = file.as_slice();
+        let val = child.utf8_text(file).unwrap();
+        let exception: KotlinExceptions = val
+            .parse()
+            .unwrap_or(KotlinExceptions::ArithmArithmeticException);
+
+        // Create a mutant for every other exception, the cardinality of the operator
+        // decides how many of them are kept
+        for mut_val in KotlinExceptions::get_all_exceptions()
+            .into_iter()
+            .filter(|e| *e != exception)
+        {
+            let mutation = Mutation::new(
+                child.start_byte(),
+                child.end_byte(),
+                mut_val.to_string(),
+                val.to_string(),
+                child.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+
+            mutations_made.push(mutation);
+        }
+    }
+
     fn mutate_literal(
         &self,
         root_node: &tree_sitter::Node,
         mutations_made: &mut Vec<Mutation>,
         file_name: &str,
+        variants: &[LiteralVariant],
     ) {
         let file = fs::read(file_name).expect("Failed to read file");
         let file = file.as_slice();
@@ -602,167 +2036,424 @@ impl MutationOperators {
         };
 
         let child_type = KotlinTypes::new(node.kind())
-            .expect(format!("Failed to convert to KotlinType: {:?}", node.kind()).as_str());
+            .unwrap_or_else(|_| panic!("Failed to convert to KotlinType: {:?}", node.kind()));
+        // A negative number is a prefix expression, so the whole expression is changed
+        let is_negative = root_node.kind() == KotlinTypes::PrefixExpression.as_str()
+            && root_node.child(0).is_some_and(|n| n.kind() == "-");
+        let (target, sign) = if is_negative {
+            (*root_node, "-")
+        } else {
+            (*node, "")
+        };
         // Change the literal to a different literal
-        let mut val = node.utf8_text(file).unwrap();
-        match child_type {
+        let val = node.utf8_text(file).unwrap();
+        let mutated_vals = match child_type {
             KotlinTypes::IntegerLiteral => {
-                let val = val.replace('_', "").parse::<i32>();
-                if val.is_err() {
-                    return;
+                match format!("{}{}", sign, val.replace('_', "")).parse::<i32>() {
+                    Ok(val) => get_integer_literal_values(
+                        val.into(),
+                        i32::MIN.into(),
+                        i32::MAX.into(),
+                        "Int",
+                        "",
+                        variants,
+                    ),
+                    Err(_) => return,
                 }
-                let val = val.unwrap();
-                // Change the value and create a mutation
-                let mutated_val = generate_random_literal(val, i32::MIN, i32::MAX);
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    mutated_val.to_string(),
-                    val.to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
-                mutations_made.push(mutation);
             }
             KotlinTypes::PrefixExpression => {
                 // In this case, we need to see the type of the prefix expression, so we need to
                 // Recurse down to the literal
-                self.mutate_literal(node, mutations_made, file_name)
+                return self.mutate_literal(node, mutations_made, file_name, variants);
             }
             KotlinTypes::StringLiteral => {
-                // Replace the string with a different string
-                let val = r#""Hello I am a Mutant!""#.to_string();
-
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    val,
-                    node.utf8_text(file).unwrap().to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
-                mutations_made.push(mutation);
-            }
-            KotlinTypes::BooleanLiteral => {
-                let val = val.parse::<bool>().unwrap();
-                // Change the value and create a mutation
-                let mutated_val = !val;
-
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    mutated_val.to_string(),
-                    val.to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
-                mutations_made.push(mutation);
+                // Replace the string with the empty string
+                if variants.contains(&LiteralVariant::EmptyString) && val != r#""""# {
+                    vec![r#""""#.to_string()]
+                } else {
+                    vec![]
+                }
+            }
+            KotlinTypes::BooleanLiteral => {
+                let val = val.parse::<bool>().unwrap();
+                vec![(!val).to_string()]
             }
             KotlinTypes::LongLiteral => {
-                // Need to strip off the l at the end
-                if val.ends_with('L') {
-                    val = val.strip_suffix('L').unwrap();
-                }
-
-                let val = val.replace('_', "").parse::<i64>();
-                if val.is_err() {
-                    return;
+                // Need to strip off the L at the end
+                let val = val.strip_suffix('L').unwrap_or(val);
+                match format!("{}{}", sign, val.replace('_', "")).parse::<i64>() {
+                    Ok(val) => {
+                        get_integer_literal_values(val, i64::MIN, i64::MAX, "Long", "L", variants)
+                    }
+                    Err(_) => return,
                 }
-                let val = val.unwrap();
-                // Change the value and create a mutation
-                let mutated_val = generate_random_literal(val, i64::MIN, i64::MAX);
-
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    mutated_val.to_string(),
-                    val.to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
-                mutations_made.push(mutation);
             }
             KotlinTypes::RealLiteral => {
                 // Need to strip off the f at the end
-                if val.ends_with('f') {
-                    val = val.strip_suffix('f').unwrap();
+                let (val, type_name, suffix) =
+                    match val.strip_suffix('f').or_else(|| val.strip_suffix('F')) {
+                        Some(val) => (val, "Float", "f"),
+                        None => (val, "Double", ""),
+                    };
+                match format!("{}{}", sign, val.replace('_', "")).parse::<f64>() {
+                    Ok(val) => get_real_literal_values(val, type_name, suffix, variants),
+                    Err(_) => return,
                 }
-                let val = val.parse::<f32>();
-                if val.is_err() {
-                    return;
-                }
-                let val = val.unwrap();
-                // Change the value and create a mutation
-                let mutated_val = generate_random_literal(val, 0.0, 1.0);
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    mutated_val.to_string(),
-                    val.to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
+            }
+            KotlinTypes::CharacterLiteral => get_character_literal_values(val, variants),
+            _ => return,
+        };
 
-                mutations_made.push(mutation);
+        let old_op = target.utf8_text(file).unwrap();
+        for mutated_val in mutated_vals {
+            // Keep a negative value from merging with an operator right before it, e.g. a--1
+            let mutated_val = if mutated_val.starts_with('-')
+                && target.start_byte() > 0
+                && matches!(file[target.start_byte() - 1], b'-' | b'+')
+            {
+                format!("({})", mutated_val)
+            } else {
+                mutated_val
+            };
+            let mutation = Mutation::new(
+                target.start_byte(),
+                target.end_byte(),
+                mutated_val,
+                old_op.to_string(),
+                target.start_position().row + 1,
+                self.clone(),
+                file_name.to_string(),
+            );
+            mutations_made.push(mutation);
+        }
+    }
+}
+
+/// Gets the values an integer literal can be changed to. The values must be between the
+/// given bounds of the type, and the suffix is added to every number
+fn get_integer_literal_values(
+    val: i64,
+    min: i64,
+    max: i64,
+    type_name: &str,
+    suffix: &str,
+    variants: &[LiteralVariant],
+) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for variant in variants {
+        let value = match variant {
+            LiteralVariant::Zero => Some(0),
+            LiteralVariant::One => Some(1),
+            LiteralVariant::MinusOne => Some(-1),
+            LiteralVariant::Increment => val.checked_add(1),
+            LiteralVariant::Decrement => val.checked_sub(1),
+            LiteralVariant::Negate => val.checked_neg(),
+            LiteralVariant::MaxValue if val != max => {
+                values.push(format!("{}.MAX_VALUE", type_name));
+                None
             }
-            KotlinTypes::CharacterLiteral => {
-                // Remove the single quotes and get the value
-                let val = val
-                    .strip_prefix('\'')
-                    .unwrap()
-                    .strip_suffix('\'')
-                    .unwrap()
-                    .chars()
-                    .next()
-                    .unwrap();
-
-                // Get a random character between 'a' and 'z'
-                let mut rnd_val = rand::thread_rng().gen_range(b'a'..=b'z') as char;
-                while rnd_val == val {
-                    rnd_val = rand::thread_rng().gen_range(b'a'..=b'z') as char;
-                }
-                let mut_val = format!("'{}'", rnd_val);
-                let mutation = Mutation::new(
-                    node.start_byte(),
-                    node.end_byte(),
-                    mut_val,
-                    val.to_string(),
-                    node.start_position().row + 1,
-                    self.clone(),
-                    file_name.to_string(),
-                );
-                mutations_made.push(mutation);
+            _ => None,
+        };
+        if let Some(value) = value.filter(|v| *v != val && (min..=max).contains(v)) {
+            values.push(format!("{}{}", value, suffix));
+        }
+    }
+    let mut seen = HashSet::new();
+    values.retain(|v| seen.insert(v.clone()));
+    values
+}
+
+/// Gets the values a floating point literal can be changed to. The suffix is added to every number
+fn get_real_literal_values(
+    val: f64,
+    type_name: &str,
+    suffix: &str,
+    variants: &[LiteralVariant],
+) -> Vec<String> {
+    let mut values: Vec<String> = Vec::new();
+    for variant in variants {
+        let value = match variant {
+            LiteralVariant::Zero => Some(0.0),
+            LiteralVariant::One => Some(1.0),
+            LiteralVariant::MinusOne => Some(-1.0),
+            LiteralVariant::Increment => Some(val + 1.0),
+            LiteralVariant::Decrement => Some(val - 1.0),
+            LiteralVariant::Negate => Some(-val),
+            LiteralVariant::MaxValue => {
+                values.push(format!("{}.MAX_VALUE", type_name));
+                None
+            }
+            LiteralVariant::NaN => {
+                values.push(format!("{}.NaN", type_name));
+                None
             }
-            _ => {}
+            LiteralVariant::EmptyString => None,
+        };
+        if let Some(value) = value.filter(|v| v.is_finite() && *v != val) {
+            values.push(format!("{:?}{}", value, suffix));
+        }
+    }
+    let mut seen = HashSet::new();
+    values.retain(|v| seen.insert(v.clone()));
+    values
+}
+
+/// Gets the values a character literal can be changed to. Escaped characters are not changed
+fn get_character_literal_values(val: &str, variants: &[LiteralVariant]) -> Vec<String> {
+    let mut chars = val
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .unwrap_or_default()
+        .chars();
+    let val = match (chars.next(), chars.next()) {
+        (Some(c), None) if c != '\\' => c as u32,
+        _ => return vec![],
+    };
+    variants
+        .iter()
+        .filter_map(|variant| match variant {
+            LiteralVariant::Increment => char::from_u32(val + 1),
+            LiteralVariant::Decrement => val.checked_sub(1).and_then(char::from_u32),
+            _ => None,
+        })
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| format!("'{}'", c))
+        .collect()
+}
+
+/// Hashes the bytes with 64-bit FNV-1a. Unlike the hashers of the standard library, its output
+/// does not change between Rust releases, so a seed makes the same random choices with any build
+fn fnv1a(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    bytes.into_iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Shifts the given bound by one in both directions. Integer literals are shifted directly,
+/// skipping a direction that overflows, any other expression gets one added or subtracted
+fn shift_by_one(bound: &str) -> Vec<String> {
+    match bound.replace('_', "").parse::<i64>() {
+        Ok(val) => [val.checked_add(1), val.checked_sub(1)]
+            .into_iter()
+            .flatten()
+            .map(|val| val.to_string())
+            .collect(),
+        Err(_) => vec![format!("{} + 1", bound), format!("{} - 1", bound)],
+    }
+}
+
+/// Checks if the lambda passed to the scope function uses its receiver, as `it` or as an
+/// explicit parameter for `also` and `let`, or as `this` for `apply` and `run`. Identifiers
+/// that are not declared before the lambda may be members of the receiver of `apply` and `run`,
+/// so they count as using it
+fn scope_function_lambda_uses_receiver(name: &Node, function: &str, file: &[u8]) -> bool {
+    let Some(lambda) = name
+        .parent()
+        .and_then(|suffix| suffix.parent())
+        .and_then(|navigation| navigation.parent())
+        .filter(|call| call.kind() == KotlinTypes::CallExpression.as_str())
+        .and_then(|call| {
+            call.children(&mut call.walk())
+                .find(|n| n.kind() == KotlinTypes::CallSuffix.as_str())
+        })
+        .and_then(|suffix| {
+            suffix
+                .children(&mut suffix.walk())
+                .find(|n| n.kind() == KotlinTypes::AnnotatedLambda.as_str())
+        })
+        .and_then(|annotated| {
+            annotated
+                .children(&mut annotated.walk())
+                .find(|n| n.kind() == KotlinTypes::LambdaLiteral.as_str())
+        })
+    else {
+        // Function references work with both kinds of scope function
+        return false;
+    };
+
+    let mut identifiers = Vec::new();
+    collect_kinds(
+        lambda,
+        &KotlinTypes::SimpleIdentifier.as_str(),
+        &mut identifiers,
+    );
+    // Identifiers after a dot are members of an explicit receiver
+    let references = identifiers.into_iter().filter(|identifier| {
+        identifier.parent().is_none_or(|parent| {
+            parent.kind() != KotlinTypes::NavigationSuffix.as_str()
+                && parent.kind() != KotlinTypes::VariableDeclaration.as_str()
+        })
+    });
+    match function {
+        "also" | "let" => {
+            lambda
+                .children(&mut lambda.walk())
+                .any(|n| n.kind() == KotlinTypes::LambdaParameters.as_str())
+                || references
+                    .into_iter()
+                    .any(|identifier| identifier.utf8_text(file) == Ok("it"))
+        }
+        "apply" | "run" => {
+            let mut this_expressions = Vec::new();
+            collect_kinds(
+                lambda,
+                &KotlinTypes::ThisExpression.as_str(),
+                &mut this_expressions,
+            );
+            let locals = declared_before(lambda, file);
+            !this_expressions.is_empty()
+                || references.into_iter().any(|identifier| {
+                    identifier
+                        .utf8_text(file)
+                        .is_ok_and(|text| !locals.contains(&text))
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Gets the names of the variables and parameters of the enclosing function that are declared
+/// before the node
+fn declared_before<'a>(node: Node, file: &'a [u8]) -> Vec<&'a str> {
+    let mut scope = node;
+    while let Some(parent) = scope.parent() {
+        scope = parent;
+        if scope.kind() == KotlinTypes::FunctionDeclaration.as_str() {
+            break;
         }
     }
+    let mut declarations = Vec::new();
+    collect_kinds(
+        scope,
+        &KotlinTypes::VariableDeclaration.as_str(),
+        &mut declarations,
+    );
+    collect_kinds(scope, &KotlinTypes::Parameter.as_str(), &mut declarations);
+    declarations
+        .into_iter()
+        .filter(|declaration| {
+            // A property can not be used in its own initializer
+            let statement = declaration
+                .parent()
+                .filter(|p| p.kind() == KotlinTypes::PropertyDeclaration.as_str())
+                .unwrap_or(*declaration);
+            statement.end_byte() <= node.start_byte()
+        })
+        .filter_map(|declaration| {
+            declaration
+                .children(&mut declaration.walk())
+                .find(|n| n.kind() == KotlinTypes::SimpleIdentifier.as_str())
+                .and_then(|name| name.utf8_text(file).ok())
+        })
+        .collect()
 }
 
-fn generate_random_literal<T>(original_literal: T, min: T, max: T) -> T
-where
-    T: std::cmp::PartialOrd + std::cmp::PartialEq + Copy + SampleUniform,
-{
-    let mut rng = rand::thread_rng();
+/// Checks if the value of the expression is not used, because it is a statement or the body of
+/// a control structure that is a statement. The last statement of a lambda is its return value,
+/// and the last statement of a braced branch or of a `try` or `catch` block is their value
+fn is_statement(node: &Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    if parent.kind() == KotlinTypes::Statements.as_str() {
+        if node.next_named_sibling().is_some() {
+            return true;
+        }
+        return match parent.parent() {
+            Some(p) if p.kind() == KotlinTypes::LambdaLiteral.as_str() => false,
+            Some(p) if p.kind() == "control_structure_body" => is_statement(&parent),
+            Some(p) if p.kind() == KotlinTypes::TryExpression.as_str() => is_statement(&p),
+            Some(p) if p.kind() == KotlinTypes::CatchBlock.as_str() => p
+                .parent()
+                .is_some_and(|try_expression| is_statement(&try_expression)),
+            _ => true,
+        };
+    }
+    if parent.kind() != "control_structure_body" {
+        return false;
+    }
+    match parent.parent() {
+        Some(structure) if structure.kind() == KotlinTypes::IfExpression.as_str() => {
+            is_statement(&structure)
+        }
+        Some(structure) if structure.kind() == KotlinTypes::WhenEntry.as_str() => structure
+            .parent()
+            .is_some_and(|when_expression| is_statement(&when_expression)),
+        // Loop bodies are never used as a value
+        Some(_) => true,
+        None => false,
+    }
+}
 
-    // Generate a random integer different from the original literal
-    let mut random_literal = rng.gen_range(min..max);
+/// Collects the nodes of the given kind under the node
+fn collect_kinds<'a>(node: Node<'a>, kind: &str, nodes: &mut Vec<Node<'a>>) {
+    if node.kind() == kind {
+        nodes.push(node);
+    }
+    for child in node.children(&mut node.walk()) {
+        collect_kinds(child, kind, nodes);
+    }
+}
 
-    // Ensure the random literal is different from the original
-    while random_literal == original_literal {
-        random_literal = rng.gen_range(min..max);
+/// Gets the "empty" values that can be returned for the given return type
+fn get_empty_return_values(return_type: &Node, file: &[u8]) -> Vec<String> {
+    if return_type.kind() == KotlinTypes::NullableType.as_str() {
+        return vec!["null".into()];
+    }
+    if return_type.kind() != KotlinTypes::UserType.as_str() {
+        return vec![];
     }
 
-    random_literal
+    // Use the last identifier so that qualified types like kotlin.Int are handled
+    let type_name = return_type
+        .children(&mut return_type.walk())
+        .filter(|node| node.kind() == KotlinTypes::TypeIdentifier.as_str())
+        .last()
+        .and_then(|node| node.utf8_text(file).ok())
+        .unwrap_or_default();
+
+    let values: &[&str] = match type_name {
+        "Int" | "Short" | "Byte" => &["0"],
+        "Long" => &["0L"],
+        "Double" => &["0.0"],
+        "Float" => &["0.0f"],
+        "String" => &[r#""""#],
+        "Boolean" => &["false", "true"],
+        "List" | "Collection" | "Iterable" => &["emptyList()"],
+        "Map" => &["emptyMap()"],
+        "Set" => &["emptySet()"],
+        "MutableList" => &["mutableListOf()"],
+        "MutableMap" => &["mutableMapOf()"],
+        "MutableSet" => &["mutableSetOf()"],
+        "Sequence" => &["emptySequence()"],
+        _ => &[],
+    };
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+/// Collects the expressions returned by unlabeled return statements in the given node,
+/// ignoring any nested functions since their returns do not belong to the outer function
+fn collect_returned_expressions<'a>(node: Node<'a>, expressions: &mut Vec<Node<'a>>) {
+    for child in node.children(&mut node.walk()) {
+        let kind = child.kind();
+        if kind == KotlinTypes::FunctionDeclaration.as_str()
+            || kind == KotlinTypes::AnonymousFunction.as_str()
+        {
+            continue;
+        }
+        if kind == KotlinTypes::JumpExpression.as_str()
+            && child.child(0).map(|n| n.kind()) == Some("return")
+        {
+            expressions.extend(child.child(1));
+            continue;
+        }
+        collect_returned_expressions(child, expressions);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{env::temp_dir, io::Write};
+    use std::{collections::BTreeMap, env::temp_dir, io::Write};
 
     use crate::mutation_tool::test_util::*;
 
@@ -778,59 +2469,343 @@ mod tests {
         parser.parse(text, None).expect("Failed to parse text")
     }
 
-    #[test]
-    fn test_arthimetic_operator() {
-        let tree = get_ast(KOTLIN_TEST_CODE);
+    #[test]
+    fn test_arthimetic_operator() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::ArithmeticReplacementOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 20);
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_relational_operator() {
+        let tree = get_ast(KOTLIN_RELATIONAL_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::RelationalReplacementOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+
+        assert_eq!(mutations_made.len(), 30);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_logical_operator() {
+        let tree = get_ast(KOTLIN_LOGICAL_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::LogicalReplacementOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 2);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_assignment_operator() {
+        let tree = get_ast(KOTLIN_ASSIGNMENT_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::AssignmentReplacementOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 25);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_unary_operator() {
+        let tree = get_ast(KOTLIN_UNARY_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::UnaryReplacementOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 12);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_unary_removal_operator() {
+        let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::UnaryRemovalOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 3);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+            assert_eq!(mutation.new_op, "RemoveOperator".to_string());
+        }
+    }
+
+    #[test]
+    fn test_not_null_assetion_operator() {
+        let tree = get_ast(KOTLIN_TEST_NULL_ASSERTION_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::NotNullAssertionOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        dbg!(&mutations_made);
+        assert_eq!(mutations_made.len(), 2);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_elvis_remove_operator() {
+        let tree = get_ast(KOTLIN_ELVIS_TEST_CODE);
+        let root = tree.root_node();
+        let mut mutations_made = Vec::new();
+        MutationOperators::ElvisRemoveOperator.mutate(
+            root,
+            &mut root.walk(),
+            None,
+            &mut mutations_made,
+            &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        assert_eq!(mutations_made.len(), 1);
+        // Assert that the old operator is not the same as the new operator
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+            assert_eq!(mutation.new_op, "RemoveOperator".to_string());
+        }
+    }
+
+    #[test]
+    fn test_elvis_literal_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_ELVIS_LITERAL_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+
+        let tree = get_ast(KOTLIN_ELVIS_LITERAL_TEST_CODE);
+
         let root = tree.root_node();
         let mut mutations_made = Vec::new();
-        MutationOperators::ArithmeticReplacementOperator.mutate(
+        MutationOperators::ElvisLiteralChangeOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 20);
+        assert_eq!(mutations_made.len(), 61);
+        // Assert that the old operator is not the same as the new operator
         for mutation in mutations_made {
             assert_ne!(mutation.old_op, mutation.new_op);
         }
     }
 
     #[test]
-    fn test_relational_operator() {
-        let tree = get_ast(KOTLIN_RELATIONAL_TEST_CODE);
+    fn test_literal_change_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("literal_change_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_LITERAL_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_LITERAL_TEST_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::RelationalReplacementOperator.mutate(
+        MutationOperators::LiteralChangeOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-
-        assert_eq!(mutations_made.len(), 30);
+        assert_eq!(mutations_made.len(), 61);
         // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
+        for mutation in &mutations_made {
             assert_ne!(mutation.old_op, mutation.new_op);
         }
+        let new_ops = |old_op: &str| {
+            mutations_made
+                .iter()
+                .filter(|m| m.old_op == old_op)
+                .map(|m| m.new_op.as_str())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(new_ops("1"), vec!["0", "-1", "2", "Int.MAX_VALUE"]);
+        assert_eq!(
+            new_ops("-2"),
+            vec!["0", "1", "-1", "-3", "2", "Int.MAX_VALUE"]
+        );
+        assert_eq!(
+            new_ops("14.0f"),
+            vec![
+                "0.0f",
+                "1.0f",
+                "-1.0f",
+                "15.0f",
+                "13.0f",
+                "-14.0f",
+                "Float.MAX_VALUE",
+                "Float.NaN"
+            ]
+        );
+        assert_eq!(new_ops("12L")[6], "Long.MAX_VALUE");
+        assert_eq!(new_ops("'c'"), vec!["'d'", "'b'"]);
+        assert_eq!(new_ops("\"a\""), vec!["\"\""]);
     }
 
     #[test]
-    fn test_logical_operator() {
-        let tree = get_ast(KOTLIN_LOGICAL_TEST_CODE);
+    fn test_literal_change_operator_uses_configured_variants() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("literal_change_variants_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_LITERAL_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_LITERAL_TEST_CODE);
+        let config = GeneralConfig {
+            literal_variants: BTreeMap::from([(
+                MutationOperators::LiteralChangeOperator,
+                vec![LiteralVariant::Negate, LiteralVariant::EmptyString],
+            )]),
+            ..Default::default()
+        };
+        let mutations_made = MutationOperators::LiteralChangeOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &config,
+        );
+        let pairs = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pairs,
+            vec![
+                ("1", "-1"),
+                ("-2", "2"),
+                ("4.0", "-4.0"),
+                ("-6.0", "6.0"),
+                ("12L", "-12L"),
+                ("-13L", "13L"),
+                ("14.0f", "-14.0f"),
+                ("-16.0f", "16.0f"),
+                ("true", "false"),
+                ("false", "true"),
+                ("\"a\"", "\"\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exception_change_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("exception_change_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_EXCEPTION_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_EXCEPTION_TEST_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::LogicalReplacementOperator.mutate(
+        MutationOperators::ExceptionChangeOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 2);
+        assert_eq!(mutations_made.len(), 3);
         // Assert that the old operator is not the same as the new operator
         for mutation in mutations_made {
             assert_ne!(mutation.old_op, mutation.new_op);
@@ -838,271 +2813,754 @@ mod tests {
     }
 
     #[test]
-    fn test_assignment_operator() {
-        let tree = get_ast(KOTLIN_ASSIGNMENT_TEST_CODE);
+    fn test_when_remove_branch_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("when_remove_branch_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_WHEN_EXPRESSION_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_WHEN_EXPRESSION_TEST_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::AssignmentReplacementOperator.mutate(
+        MutationOperators::WhenRemoveBranchOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 25);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
+        assert_eq!(mutations_made.len(), 2);
     }
 
     #[test]
-    fn test_unary_operator() {
-        let tree = get_ast(KOTLIN_UNARY_TEST_CODE);
+    fn test_label_remove_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("label_remove_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_LABEL_REMOVING_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_LABEL_REMOVING_TEST_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::UnaryReplacementOperator.mutate(
+        MutationOperators::RemoveLabelOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 12);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
+        assert_eq!(mutations_made.len(), 3);
     }
 
     #[test]
-    fn test_unary_removal_operator() {
-        let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
+    fn test_functional_binary_replacement_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("functional_find_find_last_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_FUNCTIONAL_BINARY_REPLACEMENT_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_FUNCTIONAL_BINARY_REPLACEMENT_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::UnaryRemovalOperator.mutate(
+        MutationOperators::FunctionalBinaryReplacementOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 3);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-            assert_eq!(mutation.new_op, "RemoveOperator".to_string());
-        }
+        assert_eq!(mutations_made.len(), 6);
     }
 
     #[test]
-    fn test_not_null_assetion_operator() {
-        let tree = get_ast(KOTLIN_TEST_NULL_ASSERTION_CODE);
+    fn test_functional_replacement_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("functional_any_all_none_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_FUNCTIONAL_REPLACEMENT_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_FUNCTIONAL_REPLACEMENT_CODE);
         let root = tree.root_node();
+        debug_print_ast(&root, 0);
         let mut mutations_made = Vec::new();
-        MutationOperators::NotNullAssertionOperator.mutate(
+        MutationOperators::FunctionalReplacementOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
-        dbg!(&mutations_made);
-        assert_eq!(mutations_made.len(), 2);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
+        assert_eq!(mutations_made.len(), 6);
     }
 
     #[test]
-    fn test_elvis_remove_operator() {
-        let tree = get_ast(KOTLIN_ELVIS_TEST_CODE);
+    fn test_condition_negation_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("condition_negation_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_CONDITION_NEGATION_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_CONDITION_NEGATION_TEST_CODE);
         let root = tree.root_node();
         let mut mutations_made = Vec::new();
-        MutationOperators::ElvisRemoveOperator.mutate(
+        MutationOperators::ConditionNegationOperator.mutate(
             root,
             &mut root.walk(),
             None,
             &mut mutations_made,
-            &"".into(),
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
+        );
+        // Three mutations for each condition except `if (true)`, which cannot become `true`
+        assert_eq!(mutations_made.len(), 14);
+        assert!(mutations_made.iter().any(|m| m.new_op == "!(a > b)"));
+        for mutation in mutations_made {
+            assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_return_value_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("return_value_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_RETURN_VALUE_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_RETURN_VALUE_TEST_CODE);
+        let root = tree.root_node();
+        let mutations_made = MutationOperators::ReturnValueOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        debug_print_ast(&root, 0);
+        let new_ops = mutations_made
+            .iter()
+            .map(|m| m.new_op.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            new_ops,
+            vec![
+                "null",
+                "0",
+                "0L",
+                "0.0",
+                r#""""#,
+                "false",
+                "true",
+                "emptyList()",
+                "emptyMap()",
+                "emptySet()"
+            ]
         );
-        assert_eq!(mutations_made.len(), 1);
-        // Assert that the old operator is not the same as the new operator
         for mutation in mutations_made {
             assert_ne!(mutation.old_op, mutation.new_op);
+        }
+    }
+
+    #[test]
+    fn test_statement_removal_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("statement_removal_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_STATEMENT_REMOVAL_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_STATEMENT_REMOVAL_TEST_CODE);
+        let mutations_made = MutationOperators::StatementRemovalOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let old_ops = mutations_made
+            .iter()
+            .map(|m| m.old_op.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            old_ops,
+            vec![
+                r#"logger.log("start")"#,
+                "list.add(1)",
+                "validate(list)",
+                "println(validate(list))",
+                r#"logger.log("validating")"#,
+                r#"logger.log("valid")"#,
+                "list.clear()",
+            ]
+        );
+        for mutation in mutations_made {
             assert_eq!(mutation.new_op, "RemoveOperator".to_string());
         }
     }
 
     #[test]
-    fn test_elvis_literal_operator() {
+    fn test_null_safety_operator() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("temp_file.kt");
+        let temp_file = temp_dir.join("null_safety_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_ELVIS_LITERAL_TEST_CODE.as_bytes())
+        file.write_all(KOTLIN_NULL_SAFETY_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_NULL_SAFETY_TEST_CODE);
+        let mutations_made = MutationOperators::NullSafetyOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let changes = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                ("?.let { println(it) }", "RemoveOperator"),
+                ("name?.trim()", "null"),
+                ("name?.length", "null"),
+                ("?: return", "RemoveOperator"),
+                (
+                    r#"?: throw IllegalStateException("No name")"#,
+                    "RemoveOperator"
+                ),
+            ]
+        );
+    }
 
-        let tree = get_ast(KOTLIN_ELVIS_LITERAL_TEST_CODE);
+    #[test]
+    fn test_collection_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("collection_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_COLLECTION_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_COLLECTION_TEST_CODE);
+        let mutations_made = MutationOperators::CollectionOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let changes = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                ("isEmpty", "isNotEmpty"),
+                ("isNotEmpty", "isEmpty"),
+                ("isNullOrEmpty", "isNotEmpty"),
+                (".orEmpty()", "RemoveOperator"),
+                (">", ">="),
+                ("<=", "<"),
+            ]
+        );
+    }
 
-        let root = tree.root_node();
-        let mut mutations_made = Vec::new();
-        MutationOperators::ElvisLiteralChangeOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+    #[test]
+    fn test_range_boundary_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("range_boundary_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_RANGE_BOUNDARY_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_RANGE_BOUNDARY_TEST_CODE);
+        let mutations_made = MutationOperators::RangeBoundaryOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let changes = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                // for (i in 0..n)
+                ("..", " until "),
+                ("0", "1"),
+                ("0", "-1"),
+                ("n", "n + 1"),
+                ("n", "n - 1"),
+                // for (j in 0 until n)
+                ("until", ".."),
+                ("0", "1"),
+                ("0", "-1"),
+                ("n", "n + 1"),
+                ("n", "n - 1"),
+                // for (k in n downTo 1)
+                ("n", "n + 1"),
+                ("n", "n - 1"),
+                ("1", "2"),
+                ("1", "0"),
+                // for (l in 0..10 step 2)
+                ("2", "3"),
+                ("2", "1"),
+                ("..", " until "),
+                ("0", "1"),
+                ("0", "-1"),
+                ("10", "11"),
+                ("10", "9"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range_boundary_operator_skips_overflowing_bounds() {
+        assert_eq!(
+            shift_by_one("9_223_372_036_854_775_807"),
+            vec!["9223372036854775806"]
+        );
+        assert_eq!(
+            shift_by_one("-9223372036854775808"),
+            vec!["-9223372036854775807"]
+        );
+        assert_eq!(shift_by_one("size"), vec!["size + 1", "size - 1"]);
+    }
+
+    #[test]
+    fn test_string_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("string_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_STRING_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_STRING_TEST_CODE);
+        let mutations_made = MutationOperators::StringOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let changes = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                (r#""Kraken""#, r#""""#),
+                (r#""Hello ${name.trim()} from $place!""#, r#""""#),
+                ("${name.trim()}", "RemoveOperator"),
+                ("$place", "RemoveOperator"),
+                ("trim", "trimStart"),
+                ("uppercase", "lowercase"),
+                ("startsWith", "endsWith"),
+                (r#""H""#, r#""""#),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scope_function_operator() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("scope_function_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_SCOPE_FUNCTION_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_SCOPE_FUNCTION_TEST_CODE);
+        let mutations_made = MutationOperators::ScopeFunctionOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let changes = mutations_made
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            vec![
+                ("takeIf", "takeUnless"),
+                ("takeUnless", "takeIf"),
+                ("also", "apply"),
+                ("let", "run"),
+                ("apply", "also"),
+                ("run", "let"),
+            ]
         );
-        assert_eq!(mutations_made.len(), 12);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
     }
 
     #[test]
-    fn test_literal_change_operator() {
+    fn test_override_super_call_operator() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("literal_change_temp_file.kt");
+        let temp_file = temp_dir.join("override_super_call_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_LITERAL_TEST_CODE.as_bytes())
+        file.write_all(KOTLIN_CLASS_DECLARATION_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_LITERAL_TEST_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::LiteralChangeOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+        let tree = get_ast(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mutations_made = MutationOperators::OverrideSuperCallOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let new_ops = mutations_made
+            .iter()
+            .map(|m| m.new_op.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            new_ops,
+            vec![
+                "= super.equals(other)",
+                "= super.hashCode()",
+                "= super.toString()"
+            ]
         );
-        assert_eq!(mutations_made.len(), 12);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
     }
 
     #[test]
-    fn test_exception_change_operator() {
+    fn test_data_class_property_operator() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("exception_change_temp_file.kt");
+        let temp_file = temp_dir.join("data_class_property_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_EXCEPTION_TEST_CODE.as_bytes())
+        file.write_all(KOTLIN_CLASS_DECLARATION_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_EXCEPTION_TEST_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::ExceptionChangeOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+        let tree = get_ast(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mutations_made = MutationOperators::DataClassPropertyOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        let new_ops = mutations_made
+            .iter()
+            .map(|m| m.new_op.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            new_ops,
+            vec![
+                "x: Int, var y: Int = 0, z: Int) {\n    val x: Int = x",
+                "y: Int = 0, z: Int) {\n    var y: Int = y",
+                "id: Int, val name: String) {\n    val id: Int = id\n}",
+                "name: String) {\n    val name: String = name\n}",
+            ]
         );
-        assert_eq!(mutations_made.len(), 3);
-        // Assert that the old operator is not the same as the new operator
-        for mutation in mutations_made {
-            assert_ne!(mutation.old_op, mutation.new_op);
-        }
     }
 
     #[test]
-    fn test_when_remove_branch_operator() {
+    fn test_init_block_removal_operator() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("when_remove_branch_temp_file.kt");
+        let temp_file = temp_dir.join("init_block_removal_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_WHEN_EXPRESSION_TEST_CODE.as_bytes())
+        file.write_all(KOTLIN_CLASS_DECLARATION_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_WHEN_EXPRESSION_TEST_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::WhenRemoveBranchOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+        let tree = get_ast(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mutations_made = MutationOperators::InitBlockRemovalOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
         );
-        assert_eq!(mutations_made.len(), 2);
+        assert_eq!(mutations_made.len(), 1);
+        assert_eq!(mutations_made[0].old_op, "init { require(x > 0) }");
+        assert_eq!(mutations_made[0].new_op, "RemoveOperator");
     }
 
     #[test]
-    fn test_label_remove_operator() {
+    fn test_coroutine_operators() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("label_remove_temp_file.kt");
+        let temp_file = temp_dir.join("coroutine_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_LABEL_REMOVING_TEST_CODE.as_bytes())
+        file.write_all(KOTLIN_COROUTINE_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_LABEL_REMOVING_TEST_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::RemoveLabelOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
-            &temp_file.to_str().unwrap().to_string(),
+        let tree = get_ast(KOTLIN_COROUTINE_TEST_CODE);
+        let ops = |operator: MutationOperators| {
+            operator
+                .find_mutation(
+                    &tree,
+                    &temp_file.to_str().unwrap().to_string(),
+                    &GeneralConfig::default(),
+                )
+                .into_iter()
+                .map(|m| (m.old_op, m.new_op))
+                .collect::<Vec<_>>()
+        };
+        let pair = |old: &str, new: &str| (old.to_string(), new.to_string());
+
+        assert_eq!(
+            ops(MutationOperators::CoroutineBuilderOperator),
+            vec![pair("launch", "async"), pair("async", "launch")]
+        );
+        assert_eq!(
+            ops(MutationOperators::DispatcherOperator),
+            vec![pair("IO", "Default"), pair("IO", "Main")]
+        );
+        assert_eq!(
+            ops(MutationOperators::AwaitRemovalOperator),
+            vec![
+                pair(".await()", "RemoveOperator"),
+                pair(".join()", "RemoveOperator")
+            ]
+        );
+        assert_eq!(
+            ops(MutationOperators::CancellationCheckRemovalOperator),
+            vec![
+                pair("yield()", "RemoveOperator"),
+                pair("coroutineContext.ensureActive()", "RemoveOperator")
+            ]
         );
-        assert_eq!(mutations_made.len(), 3);
     }
 
     #[test]
-    fn test_functional_binary_replacement_operator() {
+    fn test_exception_handling_operators() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("functional_find_find_last_temp_file.kt");
+        let temp_file = temp_dir.join("exception_handling_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_FUNCTIONAL_BINARY_REPLACEMENT_CODE.as_bytes())
+        file.write_all(KOTLIN_EXCEPTION_HANDLING_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_FUNCTIONAL_BINARY_REPLACEMENT_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::FunctionalBinaryReplacementOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+        let tree = get_ast(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let ops = |operator: MutationOperators| {
+            operator
+                .find_mutation(
+                    &tree,
+                    &temp_file.to_str().unwrap().to_string(),
+                    &GeneralConfig::default(),
+                )
+                .into_iter()
+                .map(|m| (m.old_op, m.new_op))
+                .collect::<Vec<_>>()
+        };
+        let pair = |old: &str, new: &str| (old.to_string(), new.to_string());
+
+        assert_eq!(
+            ops(MutationOperators::EmptyCatchOperator),
+            vec![pair("log(e)\n        retry()", "RemoveOperator")]
+        );
+        assert_eq!(
+            ops(MutationOperators::FinallyRemovalOperator),
+            vec![pair("finally {\n        close()\n    }", "RemoveOperator")]
+        );
+        assert_eq!(
+            ops(MutationOperators::ThrowRemovalOperator),
+            vec![pair("throw IllegalStateException(\"bad\")", "Unit")]
+        );
+        assert_eq!(
+            ops(MutationOperators::RunCatchingOperator),
+            vec![pair("getOrThrow", "getOrNull")]
+        );
+    }
+
+    #[test]
+    fn test_seed_hash_is_stable() {
+        // The hash must never change, or recorded seeds would no longer reproduce their runs
+        assert_eq!(fnv1a(*b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(*b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(*b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_seeded_operators_are_reproducible() {
+        let config = GeneralConfig {
+            seed: Some(42),
+            ..Default::default()
+        };
+        for (operator, code) in [
+            (
+                MutationOperators::WhenRemoveBranchOperator,
+                KOTLIN_WHEN_EXPRESSION_TEST_CODE,
+            ),
+            (
+                MutationOperators::FunctionalReplacementOperator,
+                KOTLIN_FUNCTIONAL_REPLACEMENT_CODE,
+            ),
+            (
+                MutationOperators::ExceptionChangeOperator,
+                KOTLIN_EXCEPTION_TEST_CODE,
+            ),
+        ] {
+            // Create a temp file
+            let temp_dir = temp_dir();
+            let temp_file = temp_dir.join(format!("seeded_{}_temp_file.kt", operator));
+            let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+            file.write_all(code.as_bytes())
+                .expect("Failed to write to temp file");
+            let tree = get_ast(code);
+            let find_new_ops = || {
+                operator
+                    .find_mutation(&tree, &temp_file.to_str().unwrap().to_string(), &config)
+                    .into_iter()
+                    .map(|m| (m.start_byte, m.new_op))
+                    .collect::<Vec<_>>()
+            };
+            let new_ops = find_new_ops();
+            assert!(!new_ops.is_empty());
+            for _ in 0..5 {
+                assert_eq!(new_ops, find_new_ops());
+            }
+        }
+    }
+
+    #[test]
+    fn test_when_remove_branch_operator_removes_all_branches() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("when_all_branches_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_WHEN_STATEMENT_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_WHEN_STATEMENT_TEST_CODE);
+        let config = GeneralConfig {
+            when_branch_mode: WhenBranchMode::AllBranches,
+            ..Default::default()
+        };
+        let mutations_made = MutationOperators::WhenRemoveBranchOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &config,
         );
-        assert_eq!(mutations_made.len(), 6);
+        // The else branch of the when expression is kept since its value is used
+        let removed = mutations_made
+            .iter()
+            .map(|m| {
+                String::from_utf8_lossy(
+                    &KOTLIN_WHEN_STATEMENT_TEST_CODE.as_bytes()[m.start_byte..m.end_byte],
+                )
+                .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            removed,
+            vec![
+                r#"1 -> println("one")"#,
+                r#"2 -> println("two")"#,
+                r#"else -> println("other")"#,
+                r#"1 -> "one""#,
+            ]
+        );
+        for mutation in mutations_made {
+            assert_eq!(mutation.new_op, "RemoveOperator");
+        }
     }
 
     #[test]
-    fn test_functional_replacement_operator() {
+    fn test_when_remove_branch_operator_swaps_branch_bodies() {
         // Create a temp file
         let temp_dir = temp_dir();
-        let temp_file = temp_dir.join("functional_any_all_none_temp_file.kt");
+        let temp_file = temp_dir.join("when_swap_branches_temp_file.kt");
         let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
-        file.write_all(KOTLIN_FUNCTIONAL_REPLACEMENT_CODE.as_bytes())
+        file.write_all(KOTLIN_WHEN_STATEMENT_TEST_CODE.as_bytes())
             .expect("Failed to write to temp file");
-        let tree = get_ast(KOTLIN_FUNCTIONAL_REPLACEMENT_CODE);
-        let root = tree.root_node();
-        debug_print_ast(&root, 0);
-        let mut mutations_made = Vec::new();
-        MutationOperators::FunctionalReplacementOperator.mutate(
-            root,
-            &mut root.walk(),
-            None,
-            &mut mutations_made,
+        let tree = get_ast(KOTLIN_WHEN_STATEMENT_TEST_CODE);
+        let config = GeneralConfig {
+            when_branch_mode: WhenBranchMode::AllBranchesAndSwaps,
+            ..Default::default()
+        };
+        let mutations_made = MutationOperators::WhenRemoveBranchOperator.find_mutation(
+            &tree,
             &temp_file.to_str().unwrap().to_string(),
+            &config,
         );
-        assert_eq!(mutations_made.len(), 6);
+        assert_eq!(mutations_made.len(), 7);
+        let swaps = mutations_made
+            .iter()
+            .filter(|m| m.new_op != "RemoveOperator")
+            .map(|m| m.new_op.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            swaps,
+            vec![
+                "println(\"two\")\n        2 -> println(\"one\")",
+                "println(\"other\")\n        else -> println(\"two\")",
+                "\"other\"\n        else -> \"one\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cardinality_limits_mutants_per_node() {
+        for (operator, code, alternatives) in [
+            (
+                MutationOperators::ExceptionChangeOperator,
+                KOTLIN_EXCEPTION_TEST_CODE,
+                6,
+            ),
+            (
+                MutationOperators::FunctionalReplacementOperator,
+                KOTLIN_FUNCTIONAL_REPLACEMENT_CODE,
+                2,
+            ),
+        ] {
+            // Create a temp file
+            let temp_dir = temp_dir();
+            let temp_file = temp_dir.join(format!("cardinality_{}_temp_file.kt", operator));
+            let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+            file.write_all(code.as_bytes())
+                .expect("Failed to write to temp file");
+            let tree = get_ast(code);
+            let count_mutants = |cardinality: Option<Cardinality>| {
+                let mut config = GeneralConfig {
+                    seed: Some(42),
+                    ..Default::default()
+                };
+                if let Some(cardinality) = cardinality {
+                    config.cardinality.insert(operator.clone(), cardinality);
+                }
+                operator
+                    .find_mutation(&tree, &temp_file.to_str().unwrap().to_string(), &config)
+                    .len()
+            };
+
+            // By default one random alternative is picked per node
+            let nodes = count_mutants(None);
+            assert!(nodes > 0);
+            assert_eq!(
+                count_mutants(Some(Cardinality::Exhaustive)),
+                nodes * alternatives
+            );
+            assert_eq!(count_mutants(Some(Cardinality::Sampled(2))), nodes * 2);
+            assert_eq!(
+                count_mutants(Some(Cardinality::Sampled(100))),
+                nodes * alternatives
+            );
+        }
+    }
+
+    #[test]
+    fn test_cardinality_applies_to_every_operator() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let config = GeneralConfig {
+            cardinality: BTreeMap::from([(
+                MutationOperators::ArithmeticReplacementOperator,
+                Cardinality::Sampled(1),
+            )]),
+            ..Default::default()
+        };
+        let mutations_made = MutationOperators::ArithmeticReplacementOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &config,
+        );
+        // One mutant for each of the five arithmetic operators
+        assert_eq!(mutations_made.len(), 5);
     }
 
     #[test]
@@ -1116,6 +3574,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1131,6 +3592,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1146,6 +3610,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1161,6 +3628,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1176,6 +3646,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1183,24 +3656,33 @@ mod tests {
     #[test]
     fn test_unary_removal_operator_does_not_create_mutations() {
         let tree = get_ast(KOTLIN_ASSIGNMENT_TEST_CODE);
-        let mutations_made =
-            MutationOperators::UnaryRemovalOperator.find_mutation(&tree, &"file_name".into());
+        let mutations_made = MutationOperators::UnaryRemovalOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
         assert_eq!(mutations_made.len(), 0);
     }
 
     #[test]
     fn test_unary_replacement_operator_does_not_create_mutations() {
         let tree = get_ast(KOTLIN_ASSIGNMENT_TEST_CODE);
-        let mutations_made =
-            MutationOperators::UnaryReplacementOperator.find_mutation(&tree, &"file_name".into());
+        let mutations_made = MutationOperators::UnaryReplacementOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
         assert_eq!(mutations_made.len(), 0);
     }
 
     #[test]
     fn test_not_null_assertion_operator_does_not_create_mutations() {
         let tree = get_ast(KOTLIN_ASSIGNMENT_TEST_CODE);
-        let mutations_made =
-            MutationOperators::NotNullAssertionOperator.find_mutation(&tree, &"file_name".into());
+        let mutations_made = MutationOperators::NotNullAssertionOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
         assert_eq!(mutations_made.len(), 0);
     }
 
@@ -1215,6 +3697,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1230,6 +3715,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert_eq!(mutations_made.len(), 0);
     }
@@ -1245,6 +3733,9 @@ mod tests {
             None,
             &mut mutations_made,
             &"".into(),
+            &GeneralConfig::default(),
+            &[],
+            &SymbolTable::default(),
         );
         assert!(mutations_made.is_empty());
     }
@@ -1252,16 +3743,245 @@ mod tests {
     #[test]
     fn test_functional_binary_replacement_operator_does_not_create_mutations() {
         let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
-        let mutations_made = MutationOperators::FunctionalBinaryReplacementOperator
-            .find_mutation(&tree, &"file_name".into());
+        let mutations_made = MutationOperators::FunctionalBinaryReplacementOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
         assert!(mutations_made.is_empty());
     }
 
     #[test]
     fn test_functional_replacement_operator_does_not_create_mutations() {
         let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
-        let mutations_made = MutationOperators::FunctionalReplacementOperator
-            .find_mutation(&tree, &"file_name".into());
+        let mutations_made = MutationOperators::FunctionalReplacementOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_return_value_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let mutations_made = MutationOperators::ReturnValueOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_statement_removal_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let mutations_made = MutationOperators::StatementRemovalOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_null_safety_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let mutations_made = MutationOperators::NullSafetyOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_collection_operator_does_not_create_mutations() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("collection_no_mutations_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_RELATIONAL_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_RELATIONAL_TEST_CODE);
+        let mutations_made = MutationOperators::CollectionOperator.find_mutation(
+            &tree,
+            &temp_file.to_str().unwrap().to_string(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_range_boundary_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let mutations_made = MutationOperators::RangeBoundaryOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_string_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        let mutations_made = MutationOperators::StringOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_scope_function_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
+        let mutations_made = MutationOperators::ScopeFunctionOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
+        assert!(mutations_made.is_empty());
+    }
+
+    #[test]
+    fn test_class_declaration_operators_do_not_create_mutations() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("class_declaration_no_mutations_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        for operator in [
+            MutationOperators::OverrideSuperCallOperator,
+            MutationOperators::DataClassPropertyOperator,
+            MutationOperators::InitBlockRemovalOperator,
+        ] {
+            let mutations_made = operator.find_mutation(
+                &tree,
+                &temp_file.to_str().unwrap().to_string(),
+                &GeneralConfig::default(),
+            );
+            assert!(mutations_made.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_coroutine_operators_do_not_create_mutations() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("coroutine_no_mutations_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        for operator in [
+            MutationOperators::CoroutineBuilderOperator,
+            MutationOperators::DispatcherOperator,
+            MutationOperators::AwaitRemovalOperator,
+            MutationOperators::CancellationCheckRemovalOperator,
+        ] {
+            let mutations_made = operator.find_mutation(
+                &tree,
+                &temp_file.to_str().unwrap().to_string(),
+                &GeneralConfig::default(),
+            );
+            assert!(mutations_made.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_exception_handling_operators_do_not_create_mutations() {
+        // Create a temp file
+        let temp_dir = temp_dir();
+        let temp_file = temp_dir.join("exception_handling_no_mutations_temp_file.kt");
+        let mut file = fs::File::create(&temp_file).expect("Failed to create temp file");
+        file.write_all(KOTLIN_TEST_CODE.as_bytes())
+            .expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_TEST_CODE);
+        for operator in [
+            MutationOperators::EmptyCatchOperator,
+            MutationOperators::FinallyRemovalOperator,
+            MutationOperators::ThrowRemovalOperator,
+            MutationOperators::RunCatchingOperator,
+        ] {
+            let mutations_made = operator.find_mutation(
+                &tree,
+                &temp_file.to_str().unwrap().to_string(),
+                &GeneralConfig::default(),
+            );
+            assert!(mutations_made.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_condition_negation_operator_does_not_create_mutations() {
+        let tree = get_ast(KOTLIN_UNARY_REMOVAL_TEST_CODE);
+        let mutations_made = MutationOperators::ConditionNegationOperator.find_mutation(
+            &tree,
+            &"file_name".into(),
+            &GeneralConfig::default(),
+        );
         assert!(mutations_made.is_empty());
     }
+
+    /// Finds the mutations the operator makes to the typed test code,
+    /// as (line number, old operator, new operator)
+    fn find_typed_mutations(operator: MutationOperators) -> Vec<(usize, String, String)> {
+        let temp_file = temp_dir().join(format!("typed_{}_temp_file.kt", operator));
+        fs::write(&temp_file, KOTLIN_TYPED_TEST_CODE).expect("Failed to write to temp file");
+        let tree = get_ast(KOTLIN_TYPED_TEST_CODE);
+        operator
+            .find_mutation(
+                &tree,
+                &temp_file.to_str().unwrap().to_string(),
+                &GeneralConfig::default(),
+            )
+            .into_iter()
+            .map(|m| (m.line_number, m.old_op, m.new_op))
+            .collect()
+    }
+
+    #[test]
+    fn test_arithmetic_operator_skips_string_concatenation() {
+        let mutations = find_typed_mutations(MutationOperators::ArithmeticReplacementOperator);
+        // Only the multiplication of the numbers is mutated
+        assert_eq!(mutations.len(), 4);
+        assert!(mutations
+            .iter()
+            .all(|(line, old_op, _)| *line == 9 && old_op == "*"));
+    }
+
+    #[test]
+    fn test_assignment_operator_skips_invalid_assignments() {
+        let mut mutations = find_typed_mutations(MutationOperators::AssignmentReplacementOperator);
+        mutations.sort();
+        let expected = [
+            // The string can only be added to
+            (4, "+=", "="),
+            (6, "+=", "%="),
+            (6, "+=", "*="),
+            (6, "+=", "-="),
+            (6, "+=", "/="),
+            (6, "+=", "="),
+            // The boolean can only be assigned, so line 8 has no mutations
+        ]
+        .map(|(line, old_op, new_op)| (line, old_op.to_string(), new_op.to_string()));
+        assert_eq!(mutations, expected);
+    }
+
+    #[test]
+    fn test_unary_operator_skips_invalid_operators() {
+        let mutations = find_typed_mutations(MutationOperators::UnaryReplacementOperator)
+            .into_iter()
+            .map(|(_, old_op, new_op)| (old_op, new_op))
+            .collect::<Vec<_>>();
+        assert!(mutations.contains(&("!".into(), "RemoveOperator".into())));
+        assert!(!mutations.contains(&("!".into(), "++".into())));
+        assert!(!mutations.contains(&("!".into(), "--".into())));
+        assert!(mutations.contains(&("++".into(), "--".into())));
+        assert!(!mutations.contains(&("++".into(), "!".into())));
+    }
 }
@@ -0,0 +1,300 @@
+use std::{collections::HashSet, fs, path::Path};
+
+use tree_sitter::{Query, QueryCursor};
+
+use crate::{
+    config::CustomOperatorConfig,
+    error::{MutantKrakenError, Result},
+    mutation_tool::{Mutation, MutationOperator},
+};
+
+/// A user-defined mutation operator that finds its nodes with a tree-sitter query
+/// and replaces them with a template
+pub struct QueryOperator {
+    name: String,
+    query: Query,
+    replacement: String,
+    capture_index: Option<u32>,
+}
+
+impl QueryOperator {
+    /// Loads the operator described by `config`.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The config of the custom operator.
+    /// * `project_path` - The path the query file is relative to.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the operator, or an error if the query file could not be read or parsed.
+    pub fn load(config: &CustomOperatorConfig, project_path: &Path) -> Result<Self> {
+        let query_path = project_path.join(&config.query_file);
+        let source = fs::read_to_string(&query_path).map_err(|e| {
+            MutantKrakenError::FileReadingError(format!("{}: {}", query_path.display(), e))
+        })?;
+        Self::new(config, &source)
+    }
+
+    /// Creates the operator described by `config` from the given query source
+    pub fn new(config: &CustomOperatorConfig, source: &str) -> Result<Self> {
+        let query = Query::new(&tree_sitter_kotlin::language(), source).map_err(|e| {
+            MutantKrakenError::Error(format!(
+                "Invalid query for custom operator {}: {}",
+                config.name, e
+            ))
+        })?;
+        let capture_index = match &config.capture {
+            Some(capture) => Some(query.capture_index_for_name(capture).ok_or_else(|| {
+                MutantKrakenError::Error(format!(
+                    "Custom operator {} has no capture named @{}",
+                    config.name, capture
+                ))
+            })?),
+            None => None,
+        };
+        Ok(Self {
+            name: config.name.clone(),
+            query,
+            replacement: config.replacement.clone(),
+            capture_index,
+        })
+    }
+
+    /// Runs the query on the part of the tree covered by `node` and gets each node the query
+    /// captures, with the text that replaces it
+    fn find_replacements<'tree>(
+        &self,
+        node: tree_sitter::Node<'tree>,
+        source: &str,
+    ) -> Vec<(tree_sitter::Node<'tree>, String)> {
+        // The query runs from the root, so patterns that start above the node can still match
+        let mut root = node;
+        while let Some(parent) = root.parent() {
+            root = parent;
+        }
+        let mut replacements = Vec::new();
+        let mut replaced = HashSet::new();
+        let mut cursor = QueryCursor::new();
+        cursor.set_byte_range(node.byte_range());
+        for query_match in cursor.matches(&self.query, root, source.as_bytes()) {
+            let target = match self.capture_index {
+                Some(index) => query_match.captures.iter().find(|c| c.index == index),
+                None => query_match.captures.first(),
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            // Several patterns can capture the same node
+            if !replaced.insert((target.node.start_byte(), target.node.end_byte())) {
+                continue;
+            }
+
+            let mut new_op = self.replacement.clone();
+            for capture in query_match.captures {
+                let name = self.query.capture_names()[capture.index as usize];
+                let text = &source[capture.node.start_byte()..capture.node.end_byte()];
+                new_op = new_op.replace(&format!("{{{}}}", name), text);
+            }
+            replacements.push((target.node, new_op));
+        }
+        replacements
+    }
+}
+
+impl MutationOperator for QueryOperator {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn matches(&self, node: &tree_sitter::Node, source: &str) -> bool {
+        self.find_replacements(*node, source)
+            .iter()
+            .any(|(target, _)| target == node)
+    }
+
+    fn mutate(&self, node: &tree_sitter::Node, source: &str) -> Vec<String> {
+        self.find_replacements(*node, source)
+            .into_iter()
+            .filter(|(target, _)| target == node)
+            .map(|(_, new_op)| new_op)
+            .collect()
+    }
+
+    /// Runs the query once on the whole file, instead of once for every node
+    fn find_mutation(
+        &self,
+        ast: &tree_sitter::Tree,
+        file_name: &str,
+        source: &str,
+    ) -> Vec<Mutation> {
+        self.find_replacements(ast.root_node(), source)
+            .into_iter()
+            .filter_map(|(node, new_op)| {
+                let old_op = &source[node.start_byte()..node.end_byte()];
+                if new_op == old_op {
+                    return None;
+                }
+                Some(Mutation::new(
+                    node.start_byte(),
+                    node.end_byte(),
+                    new_op,
+                    old_op.to_string(),
+                    node.start_position().row + 1,
+                    self.mutation_type(),
+                    file_name.to_string(),
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation_tool::MutationOperators;
+
+    const REQUIRE_NOT_NULL_QUERY: &str = r#"
+(call_expression
+  (simple_identifier) @fn
+  (#eq? @fn "requireNotNull"))
+"#;
+
+    const REQUIRE_NOT_NULL_CODE: &str = r#"
+fun main(name: String?) {
+    val a = requireNotNull(name)
+    val b = checkNotNull(name)
+    println(requireNotNull(a) + b)
+}
+"#;
+
+    fn parse(code: &str) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_kotlin::language())
+            .unwrap();
+        parser.parse(code, None).unwrap()
+    }
+
+    fn operator_config(replacement: &str, capture: Option<&str>) -> CustomOperatorConfig {
+        CustomOperatorConfig {
+            name: "RequireNotNullOperator".into(),
+            query_file: "require_not_null.scm".into(),
+            replacement: replacement.into(),
+            capture: capture.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_query_operator_replaces_first_capture() {
+        let operator = QueryOperator::new(
+            &operator_config("checkNotNull", None),
+            REQUIRE_NOT_NULL_QUERY,
+        )
+        .unwrap();
+        let ast = parse(REQUIRE_NOT_NULL_CODE);
+        let mutations = operator.find_mutation(&ast, "Main.kt", REQUIRE_NOT_NULL_CODE);
+
+        assert_eq!(mutations.len(), 2);
+        for (mutation, line_number) in mutations.iter().zip([3, 5]) {
+            assert_eq!(mutation.old_op, "requireNotNull");
+            assert_eq!(mutation.new_op, "checkNotNull");
+            assert_eq!(mutation.line_number, line_number);
+            assert_eq!(
+                mutation.mutation_type,
+                MutationOperators::CustomOperator("RequireNotNullOperator".into())
+            );
+            assert_eq!(
+                &REQUIRE_NOT_NULL_CODE[mutation.start_byte..mutation.end_byte],
+                "requireNotNull"
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_operator_replaces_named_capture_with_template() {
+        let query = r#"
+(call_expression
+  (simple_identifier) @fn
+  (call_suffix (value_arguments (value_argument) @arg))
+  (#eq? @fn "requireNotNull")) @call
+"#;
+        let operator =
+            QueryOperator::new(&operator_config("({arg})!!", Some("call")), query).unwrap();
+        let ast = parse(REQUIRE_NOT_NULL_CODE);
+        let mutations = operator.find_mutation(&ast, "Main.kt", REQUIRE_NOT_NULL_CODE);
+
+        let replacements = mutations
+            .iter()
+            .map(|m| (m.old_op.as_str(), m.new_op.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            replacements,
+            vec![
+                ("requireNotNull(name)", "(name)!!"),
+                ("requireNotNull(a)", "(a)!!")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_operator_matches_captured_nodes() {
+        let operator = QueryOperator::new(
+            &operator_config("checkNotNull", None),
+            REQUIRE_NOT_NULL_QUERY,
+        )
+        .unwrap();
+        let ast = parse(REQUIRE_NOT_NULL_CODE);
+        let identifier = ast
+            .root_node()
+            .descendant_for_byte_range(
+                REQUIRE_NOT_NULL_CODE.find("requireNotNull").unwrap(),
+                REQUIRE_NOT_NULL_CODE.find("requireNotNull").unwrap(),
+            )
+            .unwrap();
+        let call = identifier.parent().unwrap();
+
+        assert!(operator.matches(&identifier, REQUIRE_NOT_NULL_CODE));
+        assert!(!operator.matches(&call, REQUIRE_NOT_NULL_CODE));
+        assert_eq!(
+            operator.mutate(&identifier, REQUIRE_NOT_NULL_CODE),
+            vec!["checkNotNull"]
+        );
+    }
+
+    #[test]
+    fn test_query_operator_rejects_invalid_query() {
+        let result = QueryOperator::new(&operator_config("x", None), "(call_expression");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_query_operator_rejects_unknown_capture() {
+        let result = QueryOperator::new(
+            &operator_config("x", Some("missing")),
+            REQUIRE_NOT_NULL_QUERY,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_query_operator_load_reads_query_file() {
+        let project_path = std::env::temp_dir().join("query_operator_load");
+        fs::create_dir_all(&project_path).unwrap();
+        fs::write(
+            project_path.join("require_not_null.scm"),
+            REQUIRE_NOT_NULL_QUERY,
+        )
+        .unwrap();
+
+        assert!(QueryOperator::load(&operator_config("x", None), &project_path).is_ok());
+        assert!(QueryOperator::load(
+            &CustomOperatorConfig {
+                query_file: "missing.scm".into(),
+                ..operator_config("x", None)
+            },
+            &project_path
+        )
+        .is_err());
+    }
+}
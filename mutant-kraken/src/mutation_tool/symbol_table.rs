@@ -0,0 +1,274 @@
+use std::ops::Range;
+
+/// The numeric types of Kotlin
+const NUMERIC_TYPES: [&str; 8] = [
+    "Byte", "Short", "Int", "Long", "Float", "Double", "UInt", "ULong",
+];
+
+/// A variable, property or parameter declared in a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Symbol {
+    name: String,
+    type_name: String,
+    /// The bytes of the file the symbol can be used in
+    scope: Range<usize>,
+}
+
+/// The declared types of the locals, properties and parameters of a file.
+///
+/// Types are only collected from the declarations of the file, either from the type written
+/// in the declaration or from the literal the declaration is initialized with. Anything that
+/// can not be worked out from the file has an unknown type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolTable {
+    source: String,
+    symbols: Vec<Symbol>,
+}
+
+impl SymbolTable {
+    /// Creates the symbol table of the file with the given AST and source code
+    pub fn new(root: &tree_sitter::Node, source: &str) -> Self {
+        let mut symbol_table = Self {
+            source: source.to_string(),
+            symbols: Vec::new(),
+        };
+        symbol_table.collect(root);
+        symbol_table
+    }
+
+    /// Collects the declarations of `node` and its children
+    fn collect(&mut self, node: &tree_sitter::Node) {
+        match node.kind() {
+            "property_declaration" => self.collect_property(node),
+            // Parameters can be used in the whole function or class they belong to
+            "parameter" | "class_parameter" => {
+                let owner = node.parent().and_then(|p| p.parent());
+                if let Some(owner) = owner {
+                    self.collect_typed(node, owner.start_byte()..owner.end_byte());
+                }
+            }
+            _ => {}
+        }
+        for child in node.children(&mut node.walk()) {
+            self.collect(&child);
+        }
+    }
+
+    /// Collects a property. It can be used from the declaration to the end of the
+    /// block it is declared in
+    fn collect_property(&mut self, node: &tree_sitter::Node) {
+        let (Some(declaration), Some(block)) = (
+            node.children(&mut node.walk())
+                .find(|c| c.kind() == "variable_declaration"),
+            node.parent(),
+        ) else {
+            return;
+        };
+        let scope = node.start_byte()..block.end_byte();
+        if self.collect_typed(&declaration, scope.clone()) {
+            return;
+        }
+
+        // Work out the type from the value the property is initialized with
+        let initializer = node
+            .children(&mut node.walk())
+            .skip_while(|c| c.kind() != "=")
+            .nth(1);
+        let Some(initializer) = initializer else {
+            return;
+        };
+        if let (Some(name), Some(type_name)) = (
+            self.identifier(&declaration),
+            self.expression_type(&initializer),
+        ) {
+            self.symbols.push(Symbol {
+                name,
+                type_name,
+                scope,
+            });
+        }
+    }
+
+    /// Collects a declaration that has its type written in it.
+    /// Returns false if the declaration has no type
+    fn collect_typed(&mut self, node: &tree_sitter::Node, scope: Range<usize>) -> bool {
+        let type_node = node
+            .children(&mut node.walk())
+            .skip_while(|c| c.kind() != ":")
+            .nth(1);
+        match (
+            self.identifier(node),
+            type_node.and_then(|t| self.type_name(&t)),
+        ) {
+            (Some(name), Some(type_name)) => {
+                self.symbols.push(Symbol {
+                    name,
+                    type_name,
+                    scope,
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Gets the name of the first identifier of `node`
+    fn identifier(&self, node: &tree_sitter::Node) -> Option<String> {
+        node.children(&mut node.walk())
+            .find(|c| c.kind() == "simple_identifier")
+            .map(|c| self.text(&c).to_string())
+    }
+
+    /// Gets the name of a type, without its package, type arguments or nullability
+    fn type_name(&self, node: &tree_sitter::Node) -> Option<String> {
+        match node.kind() {
+            "user_type" => node
+                .children(&mut node.walk())
+                .filter(|c| c.kind() == "type_identifier")
+                .last()
+                .map(|c| self.text(&c).to_string()),
+            "nullable_type" | "parenthesized_type" => {
+                node.named_child(0).and_then(|inner| self.type_name(&inner))
+            }
+            _ => None,
+        }
+    }
+
+    fn text(&self, node: &tree_sitter::Node) -> &str {
+        self.source.get(node.byte_range()).unwrap_or_default()
+    }
+
+    /// Gets the type of the symbol with the given name that can be used at `byte`
+    pub fn lookup(&self, name: &str, byte: usize) -> Option<&str> {
+        self.symbols
+            .iter()
+            .filter(|s| s.name == name && s.scope.contains(&byte))
+            // The innermost declaration shadows the others
+            .max_by_key(|s| s.scope.start)
+            .map(|s| s.type_name.as_str())
+    }
+
+    /// Gets the type of an expression, if it can be worked out from the file
+    pub fn expression_type(&self, node: &tree_sitter::Node) -> Option<String> {
+        match node.kind() {
+            "string_literal" => Some("String".into()),
+            "integer_literal" | "hex_literal" | "bin_literal" => Some("Int".into()),
+            "long_literal" => Some("Long".into()),
+            "unsigned_literal" => Some("UInt".into()),
+            "real_literal" => {
+                if self.text(node).ends_with(['f', 'F']) {
+                    Some("Float".into())
+                } else {
+                    Some("Double".into())
+                }
+            }
+            "boolean_literal" => Some("Boolean".into()),
+            "character_literal" => Some("Char".into()),
+            "simple_identifier" => self
+                .lookup(self.text(node), node.start_byte())
+                .map(String::from),
+            "parenthesized_expression" | "prefix_expression" => node
+                .named_children(&mut node.walk())
+                .last()
+                .and_then(|inner| self.expression_type(&inner)),
+            "additive_expression" | "multiplicative_expression" => {
+                let left = node.named_child(0).and_then(|n| self.expression_type(&n));
+                let right = node
+                    .named_child(node.named_child_count().saturating_sub(1))
+                    .and_then(|n| self.expression_type(&n));
+                // Adding anything to a string creates a string
+                if node.kind() == "additive_expression"
+                    && (left.as_deref() == Some("String") || right.as_deref() == Some("String"))
+                {
+                    return Some("String".into());
+                }
+                left.filter(|l| is_numeric(l))
+            }
+            // Calls to a constructor have the type of the class
+            "call_expression" => node
+                .named_child(0)
+                .filter(|callee| callee.kind() == "simple_identifier")
+                .map(|callee| self.text(&callee).to_string())
+                .filter(|callee| callee.starts_with(|c: char| c.is_ascii_uppercase())),
+            _ => None,
+        }
+    }
+}
+
+/// Checks if the type is one of the numeric types of Kotlin
+pub fn is_numeric(type_name: &str) -> bool {
+    NUMERIC_TYPES.contains(&type_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SYMBOL_TEST_CODE: &str = r#"
+class Account(val id: Int, var owner: String?) {
+    var balance = 0L
+
+    fun deposit(amount: Double, note: kotlin.String): String {
+        val label: String = "deposit"
+        var message = label + amount
+        val ratio = 1.5f
+        val copy = Account(id, owner)
+        if (true) {
+            val label = 'c'
+            println(label)
+        }
+        return message + label
+    }
+}
+"#;
+
+    fn symbol_table() -> SymbolTable {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_kotlin::language())
+            .unwrap();
+        let tree = parser.parse(SYMBOL_TEST_CODE, None).unwrap();
+        SymbolTable::new(&tree.root_node(), SYMBOL_TEST_CODE)
+    }
+
+    /// Gets the byte right after the nth occurrence of `text`
+    fn byte_after(text: &str, occurrence: usize) -> usize {
+        SYMBOL_TEST_CODE
+            .match_indices(text)
+            .nth(occurrence)
+            .map(|(i, _)| i + text.len())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_symbol_table_collects_declared_types() {
+        let symbols = symbol_table();
+        let body = byte_after("return", 0);
+        assert_eq!(symbols.lookup("id", body), Some("Int"));
+        assert_eq!(symbols.lookup("owner", body), Some("String"));
+        assert_eq!(symbols.lookup("amount", body), Some("Double"));
+        assert_eq!(symbols.lookup("note", body), Some("String"));
+        assert_eq!(symbols.lookup("label", body), Some("String"));
+    }
+
+    #[test]
+    fn test_symbol_table_infers_types_from_initializers() {
+        let symbols = symbol_table();
+        let body = byte_after("return", 0);
+        assert_eq!(symbols.lookup("balance", body), Some("Long"));
+        assert_eq!(symbols.lookup("message", body), Some("String"));
+        assert_eq!(symbols.lookup("ratio", body), Some("Float"));
+        assert_eq!(symbols.lookup("copy", body), Some("Account"));
+    }
+
+    #[test]
+    fn test_symbol_table_uses_innermost_declaration() {
+        let symbols = symbol_table();
+        assert_eq!(
+            symbols.lookup("label", byte_after("println(", 0)),
+            Some("Char")
+        );
+        assert_eq!(symbols.lookup("amount", 0), None);
+        assert_eq!(symbols.lookup("unknown", byte_after("return", 0)), None);
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::Instant;
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     ffi::OsStr,
     fs,
     io::BufRead,
@@ -13,14 +13,17 @@ use regex::Regex;
 
 use cli_table::{Table, WithTitle};
 
+use crate::build_system::{self, BuildSystem};
 use crate::cli::MutationCommandConfig;
 use crate::config::MutantKrakenConfig;
+use crate::coverage::{package_of, CoverageMap};
 use crate::error::{self, MutantKrakenError, Result};
+use crate::html_gen;
 use crate::mutation_tool::{
-    mutation::{FileMutations, Mutation, MutationResult},
-    MutationOperators,
+    is_equivalent,
+    mutation::{mutation_score, FileMutations, Mutation, MutationResult},
+    MutationOperator, MutationOperators, QueryOperator,
 };
-use crate::{gradle, html_gen};
 
 use super::MutationToolBuilder;
 
@@ -30,6 +33,8 @@ pub struct MutationTool {
     parser: Arc<Mutex<tree_sitter::Parser>>,
     pub mutate_config: MutationCommandConfig,
     pub mutation_operators: Arc<Vec<MutationOperators>>,
+    pub custom_operators: Arc<Vec<Box<dyn MutationOperator>>>,
+    pub build_system: Arc<dyn BuildSystem>,
     pub output_directory: String,
     pub mutation_dir: PathBuf,
     pub backup_dir: PathBuf,
@@ -40,7 +45,9 @@ pub struct MutationTool {
 
 impl Default for MutationTool {
     fn default() -> Self {
-        MutationToolBuilder::new().build()
+        MutationToolBuilder::new()
+            .build()
+            .expect("Failed to build the default mutation tool")
     }
 }
 
@@ -53,6 +60,7 @@ impl MutationTool {
     /// * `mutantkraken_config` - The MutantKraken configuration.
     /// * `output_directory` - The output directory for the mutated files.
     /// * `mutation_operators` - The mutation operators to use.
+    /// * `custom_operators` - The operators implemented outside of this crate to use. The operators defined in the config are added to them.
     /// * `enable_mutation_comment` - Whether to enable mutation comments in the mutated files.
     ///
     /// # Returns
@@ -63,6 +71,7 @@ impl MutationTool {
         mutantkraken_config: MutantKrakenConfig,
         output_directory: String,
         mutation_operators: Vec<MutationOperators>,
+        mut custom_operators: Vec<Box<dyn MutationOperator>>,
         enable_mutation_comment: bool,
     ) -> Result<Self> {
         let mut parser = tree_sitter::Parser::new();
@@ -82,6 +91,14 @@ impl MutationTool {
             return Err(MutantKrakenError::Error("Path is not a directory".into()));
         }
 
+        // Load the custom operators defined in the config
+        for custom_operator in mutantkraken_config.general.custom_operators.iter() {
+            custom_operators.push(Box::new(QueryOperator::load(custom_operator, config_path)?));
+        }
+
+        let build_system = build_system::from_config(&mutantkraken_config, config_path);
+        tracing::info!("Building the project with {}", build_system.name());
+
         // Create directories
         // If the Backups directory exists, delete it
         let backup_dir = Path::new(&output_directory).join("backups");
@@ -112,6 +129,8 @@ impl MutationTool {
             mutate_config,
             parser: Arc::new(Mutex::new(parser)),
             mutation_operators: Arc::new(mutation_operators),
+            custom_operators: Arc::new(custom_operators),
+            build_system: Arc::from(build_system),
             output_directory,
             mutation_dir,
             backup_dir,
@@ -151,17 +170,26 @@ impl MutationTool {
     /// building and testing, reporting results, saving results in csv, and generating an HTML report.
     pub fn mutate(&mut self) -> Result<()> {
         tracing::info!("Mutation tool started...");
+        // Pick a seed when none is set, so that the run can be reproduced
+        let seed = *self
+            .mutantkraken_config
+            .general
+            .seed
+            .get_or_insert_with(rand::random);
+        tracing::info!("Using seed: {}", seed);
         // Phase 1: Get files from project
         println!("[1/6] 📂 Gathering files...");
         let mut existing_files = self.get_files_from_project()?;
         // Phase 2: Gather mutations per file
         println!("[2/6] 🔎 Gathering mutations...");
-        let file_mutations = self.gather_mutations_per_file(&mut existing_files)?;
+        let mut file_mutations = self.gather_mutations_per_file(&mut existing_files)?;
+        // Mark the mutants that can never be killed, so they are not built
+        self.mark_equivalent_mutations(&mut file_mutations)?;
         // Store all mutations in a json file
         self.store_mutations(&file_mutations)?;
         // Phase 3: Generate mutations per file
         println!("[3/6] 🔨 Generating mutations...");
-        self.generate_mutations_per_file(&file_mutations)?;
+        self.generate_mutations_per_file(&mut file_mutations)?;
         // Phase 4: Build and test
         println!("[4/6] 🏗 Building and testing...");
         let mutations = self.build_and_test(&file_mutations)?;
@@ -173,7 +201,7 @@ impl MutationTool {
         self.save_results(&mutations)?;
         // Phase 7: Generate HTML Report
         println!("[7/7] 📊 Generating HTML report...");
-        html_gen::build_html_page(&mutations, Path::new(self.output_directory.as_str()));
+        html_gen::build_html_page(&mutations, Path::new(self.output_directory.as_str()), seed);
         Ok(())
     }
 
@@ -183,8 +211,11 @@ impl MutationTool {
             Path::new(self.output_directory.as_str())
                 .join("mutations")
                 .join("mutations.json"),
-            serde_json::to_string_pretty(file_mutations)
-                .map_err(|_| error::MutantKrakenError::ConversionError)?,
+            serde_json::to_string_pretty(&serde_json::json!({
+                "seed": self.mutantkraken_config.general.seed,
+                "files": file_mutations,
+            }))
+            .map_err(|_| error::MutantKrakenError::ConversionError)?,
         )
         .map_err(|e| error::MutantKrakenError::Error(e.to_string()))?;
 
@@ -249,10 +280,14 @@ impl MutationTool {
         let mut total_killed_mutants = 0;
         let mut total_survived_mutants = 0;
         let mut total_timeouts_or_build_fails = 0;
+        let mut total_invalid_mutants = 0;
+        let mut total_equivalent_mutants = 0;
         total_mutations += mutations.len();
         mutations.iter().for_each(|m| match m.result {
             MutationResult::Killed => total_killed_mutants += 1,
             MutationResult::Survived => total_survived_mutants += 1,
+            MutationResult::Invalid => total_invalid_mutants += 1,
+            MutationResult::Equivalent => total_equivalent_mutants += 1,
             _ => total_timeouts_or_build_fails += 1,
         });
         if self.mutantkraken_config.output.display_end_table {
@@ -274,18 +309,62 @@ impl MutationTool {
                 "Total timeouts or build fails".to_string(),
                 total_timeouts_or_build_fails.to_string(),
             ],
+            vec![
+                "Total invalid mutants".to_string(),
+                total_invalid_mutants.to_string(),
+            ],
+            vec![
+                "Total equivalent mutants".to_string(),
+                total_equivalent_mutants.to_string(),
+            ],
             vec![
                 "Mutation score".to_string(),
-                format!(
-                    "{}%",
-                    (total_killed_mutants as f32
-                        / (total_killed_mutants + total_survived_mutants) as f32)
-                        * 100.0
-                ),
+                mutation_score(mutations.iter()),
+            ],
+            vec![
+                "Seed".to_string(),
+                self.mutantkraken_config
+                    .general
+                    .seed
+                    .map_or("None".to_string(), |seed| seed.to_string()),
             ],
         ]
         .table();
         cli_table::print_stdout(table)?;
+
+        // Break the results down per module, for projects with modules
+        let mut module_mutations: BTreeMap<&str, Vec<&Mutation>> = BTreeMap::new();
+        for mutation in mutations {
+            if let Some(module) = &mutation.module {
+                module_mutations.entry(module).or_default().push(mutation);
+            }
+        }
+        if !module_mutations.is_empty() {
+            let table = module_mutations
+                .iter()
+                .map(|(module, mutations)| {
+                    let count = |result| mutations.iter().filter(|m| m.result == result).count();
+                    vec![
+                        module.to_string(),
+                        mutations.len().to_string(),
+                        count(MutationResult::Survived).to_string(),
+                        count(MutationResult::Killed).to_string(),
+                        count(MutationResult::Equivalent).to_string(),
+                        mutation_score(mutations.iter().copied()),
+                    ]
+                })
+                .collect::<Vec<_>>()
+                .table()
+                .title(vec![
+                    "Module",
+                    "Total mutations",
+                    "Survived mutants",
+                    "Killed mutants",
+                    "Equivalent mutants",
+                    "Mutation score",
+                ]);
+            cli_table::print_stdout(table)?;
+        }
         Ok(())
     }
 
@@ -319,7 +398,10 @@ impl MutationTool {
         &mut self,
         file_mutations: &HashMap<String, FileMutations>,
     ) -> Result<Vec<Mutation>> {
-        self.gradle_checks()?;
+        self.build_system
+            .baseline_check(Path::new(&self.mutate_config.path))?;
+        // The coverage reports are written while the tests of the baseline check run
+        let coverage = Arc::new(self.load_coverage());
 
         // Get total number of mutations
         let num_mutations = file_mutations
@@ -342,6 +424,7 @@ impl MutationTool {
             .num_threads(chunks.len())
             .build()
             .map_err(|e| MutantKrakenError::Error(e.to_string()))?;
+        let mut worker_dirs = Vec::new();
         thread_pool.scope(|s| {
             for chunck in chunks.iter_mut() {
                 // Create unique temp directory
@@ -360,13 +443,30 @@ impl MutationTool {
                 }
                 fs::create_dir_all(&td).expect("Failed to create temp directory");
                 create_temp_directory(dir, &td).expect("Failed to create temp directory");
-                // Run gradle build and tests in parallel
+                worker_dirs.push(td.clone());
+                // Run the builds and tests in parallel
                 let path = path.clone();
+                let build_system = self.build_system.clone();
+                let coverage = coverage.clone();
                 let mutation_dir = mutation_dir.clone();
                 let backup_dir = backup_dir.clone();
                 let progress_bar = progress_bar.clone();
                 s.spawn(move |_| {
+                    // The copy is cleaned once, so the mutants of this worker are built incrementally
+                    if let Err(err) = build_system.prepare_worker(&td) {
+                        tracing::error!("An error occurred preparing {}: {}", td.display(), err);
+                    }
+                    // The package of each file, which the coverage reports are keyed by
+                    let mut packages = HashMap::new();
                     chunck.iter_mut().for_each(|mutation| {
+                        // Invalid and equivalent mutants were never written, so there is nothing to build
+                        if matches!(
+                            mutation.result,
+                            MutationResult::Invalid | MutationResult::Equivalent
+                        ) {
+                            progress_bar.inc(1);
+                            return;
+                        }
                         let original_file_name = mutation.file_name.clone();
                         let file_name = Path::new(&original_file_name)
                             .strip_prefix(path.as_ref())
@@ -385,10 +485,22 @@ impl MutationTool {
                                 .expect("Failed to convert file name to string")
                         ));
 
-                        if let Err(err) = gradle::run(
-                            &PathBuf::from(&td),
+                        let covering_tests = coverage.as_ref().as_ref().and_then(|c| {
+                            let package =
+                                packages.entry(file_name.to_path_buf()).or_insert_with(|| {
+                                    package_of(
+                                        &fs::read_to_string(&original_file_path)
+                                            .unwrap_or_default(),
+                                    )
+                                });
+                            c.tests_for(package, file_name, mutation.line_number)
+                        });
+                        if let Err(err) = build_system::run(
+                            build_system.as_ref(),
+                            &td,
                             &mutated_file_path,
                             &original_file_path,
+                            covering_tests.as_deref(),
                             mutation,
                         ) {
                             tracing::error!("An error occurred building and testing: {}", err);
@@ -409,6 +521,11 @@ impl MutationTool {
             }
         });
         progress_bar.finish();
+        for dir in worker_dirs {
+            if let Err(err) = self.build_system.finish_worker(&dir) {
+                tracing::error!("An error occurred finishing {}: {}", dir.display(), err);
+            }
+        }
         // Delete temp directory
         if let Err(err) = fs::remove_dir_all(Path::new(self.output_directory.as_str()).join("temp"))
         {
@@ -421,42 +538,58 @@ impl MutationTool {
         Ok(chunks.into_iter().flatten().collect())
     }
 
-    fn gradle_checks(&mut self) -> Result<()> {
-        let path = PathBuf::from(&self.mutate_config.path);
-        if !gradle::build_project_success(&path)? {
-            return Err(MutantKrakenError::Error(
-                "Project does not build successfully. Please fix the errors and try again.".into(),
-            ));
-        }
-        if !gradle::project_tests_pass(&path)? {
-            return Err(MutantKrakenError::Error(
-                "Project tests do not pass. Please fix the errors and try again.".into(),
-            ));
+    /// Loads the per-test coverage reports set in the config, so each mutant only runs the tests
+    /// that cover it. Returns `None` when there are no reports, and the tests of each file are run
+    fn load_coverage(&self) -> Option<CoverageMap> {
+        let report_dir = self.mutantkraken_config.general.coverage_reports.as_ref()?;
+        match CoverageMap::load(&Path::new(&self.mutate_config.path).join(report_dir)) {
+            Ok(coverage) => Some(coverage),
+            Err(e) => {
+                println!("[WARNING] ⚠️  Could not read the coverage reports, view logs for error.");
+                tracing::warn!(
+                    "Could not read the coverage reports, running the tests of each file. Error: {}",
+                    e
+                );
+                None
+            }
         }
-        Ok(())
     }
 
     /// Generates mutations for each file in the given `file_mutations` HashMap.
     /// Each mutation is applied to the corresponding file, and the resulting mutated file is written to
     /// the output directory specified in the `MutationTool` configuration.
+    /// Mutated files that no longer parse are not written, and their mutation is marked as `Invalid`.
     fn generate_mutations_per_file(
         &self,
-        file_mutations: &HashMap<String, FileMutations>,
+        file_mutations: &mut HashMap<String, FileMutations>,
     ) -> Result<()> {
         tracing::info!("Generating mutations per file");
         let start = Instant::now();
         self.thread_pool.scope(|s| {
             // Iterate over each file and generate mutations
-            file_mutations.iter().for_each(|(file_name, fm)| {
+            file_mutations.iter_mut().for_each(|(file_name, fm)| {
                 let file_str = fs::read_to_string(file_name).expect("Failed to read file");
+                let parser = self.parser.clone();
                 s.spawn(move |_| {
+                    // A file that already has syntax errors can not tell which mutants are invalid
+                    let original_has_error = parse_has_error(&parser, file_str.as_bytes());
                     // Iterate over each mutation and apply it to the file
-                    fm.mutations.iter().for_each(|m| {
-                        let new_op_bytes = m.new_op.as_bytes();
+                    fm.mutations.iter_mut().for_each(|m| {
+                        // Equivalent mutants are never built, so they do not need a file
+                        if m.result == MutationResult::Equivalent {
+                            return;
+                        }
+                        let new_op_bytes = m.replacement().as_bytes();
                         let mut file = file_str.as_bytes().to_vec();
 
                         // Add the mutation to the vector of bytes
                         file.splice(m.start_byte..m.end_byte, new_op_bytes.iter().cloned());
+                        // Skip the mutants that do not parse, they would only fail to build
+                        if !original_has_error && parse_has_error(&parser, &file) {
+                            tracing::info!("Mutation {} does not parse, skipping it", m.id);
+                            m.result = MutationResult::Invalid;
+                            return;
+                        }
                         // Add comment above mutation about the mutation
                         let file = file
                             .lines()
@@ -519,21 +652,39 @@ impl MutationTool {
                 let file_mutations = file_mutations.clone();
                 let parser = self.parser.clone();
                 let mutation_operators = self.mutation_operators.clone();
+                let custom_operators = self.custom_operators.clone();
+                let general_config = self.mutantkraken_config.general.clone();
+                let build_system = self.build_system.clone();
+                let project_path = self.mutate_config.path.clone();
 
                 // Spawn a thread for each file
                 s.spawn(move |_| {
                     // Parse the file content using the parser
+                    let source = fs::read_to_string(&file).expect("File Not Found!");
                     let ast = parser
                         .lock()
                         .expect("Failed to lock parser")
-                        .parse(fs::read_to_string(&file).expect("File Not Found!"), None)
+                        .parse(&source, None)
                         .expect("Parsing file failed");
 
                     // Iterate through mutation operators to find mutations
-                    for mut_op in mutation_operators.iter() {
-                        // Get a list of mutations that can be made
-                        let mutations = mut_op.find_mutation(&ast, file);
-
+                    let mutations_per_operator = mutation_operators
+                        .iter()
+                        .map(|mut_op| mut_op.find_mutation(&ast, file, &general_config))
+                        .chain(
+                            custom_operators
+                                .iter()
+                                .map(|custom_op| custom_op.find_mutation(&ast, file, &source)),
+                        );
+                    // Record the module of the file, so the results can be broken down per module
+                    let module = Path::new(file)
+                        .strip_prefix(&project_path)
+                        .ok()
+                        .and_then(|file| build_system.module_of(file));
+                    for mut mutations in mutations_per_operator {
+                        mutations
+                            .iter_mut()
+                            .for_each(|mutation| mutation.module.clone_from(&module));
                         // Update mutation count and file mutations
                         *mutation_count
                             .lock()
@@ -578,6 +729,41 @@ impl MutationTool {
             .map_err(|_| MutantKrakenError::Error("Failed to unwrap file_mutations".to_string()))
     }
 
+    /// Marks the mutations that can never be killed as `Equivalent`, using the rules of `is_equivalent`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_mutations` - A hashmap containing the mutations for each file.
+    ///
+    fn mark_equivalent_mutations(
+        &self,
+        file_mutations: &mut HashMap<String, FileMutations>,
+    ) -> Result<()> {
+        let mut equivalent_count = 0;
+        for (file_name, fm) in file_mutations.iter_mut() {
+            let source = fs::read_to_string(file_name)?;
+            let ast = self
+                .parser
+                .lock()
+                .map_err(|_| MutantKrakenError::Error("Failed to lock parser".into()))?
+                .parse(&source, None)
+                .ok_or(MutantKrakenError::Error("Parsing file failed".into()))?;
+            for mutation in fm.mutations.iter_mut() {
+                if is_equivalent(
+                    mutation,
+                    &ast.root_node(),
+                    &source,
+                    &self.mutantkraken_config.general,
+                ) {
+                    mutation.result = MutationResult::Equivalent;
+                    equivalent_count += 1;
+                }
+            }
+        }
+        tracing::info!("Equivalent mutations found: {}", equivalent_count);
+        Ok(())
+    }
+
     /// Gets all files from the given directory and adds them to the given vector.
     /// This function is recursive, so it will also get files from subdirectories.
     /// It will ignore files and directories that match the ignore patterns in the `MutantKrakenConfig`.
@@ -674,7 +860,7 @@ impl MutationTool {
 }
 
 /// Recursively creates a temporary directory and copies all files from the given directory into it,
-/// excluding the "mutant-kraken-dist" folder. If a file is named "gradlew" or "gradlew.bat", it is copied
+/// excluding the "mutant-kraken-dist" folder. If a file is a Gradle or Maven wrapper script, it is copied
 /// to the temporary directory with the same permissions. All other files are written to the temporary
 /// directory with their original contents.
 ///
@@ -705,7 +891,7 @@ fn create_temp_directory(dir: PathBuf, temp_dir: &Path) -> Result<()> {
             create_temp_directory(path, &temp_dir)?;
         } else {
             let file_contents = fs::read(&path)?;
-            if file_name == "gradlew" || file_name == "gradlew.bat" {
+            if ["gradlew", "gradlew.bat", "mvnw", "mvnw.cmd"].contains(&file_name) {
                 // We copy here so that we keep the same permissions
                 fs::copy(&path, temp_dir.join(file_name))?;
             } else {
@@ -716,6 +902,15 @@ fn create_temp_directory(dir: PathBuf, temp_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Parses the source and checks if its tree has any `ERROR` or `MISSING` nodes
+fn parse_has_error(parser: &Mutex<tree_sitter::Parser>, source: &[u8]) -> bool {
+    parser
+        .lock()
+        .expect("Failed to lock parser")
+        .parse(source, None)
+        .is_none_or(|tree| tree.root_node().has_error())
+}
+
 fn create_mutation_chucks(file_mutations: &HashMap<String, FileMutations>) -> Vec<Vec<Mutation>> {
     // Merge all mutants into one vector
     let all_mutations: Vec<Mutation> = file_mutations
@@ -751,7 +946,10 @@ mod tests {
 
     use uuid::Uuid;
 
-    use crate::mutation_tool::test_util::*;
+    use crate::{
+        config::CustomOperatorConfig,
+        mutation_tool::{test_util::*, WhenBranchMode},
+    };
 
     use super::*;
 
@@ -779,10 +977,12 @@ mod tests {
         MutationTool::new(
             MutationCommandConfig {
                 path: format!("./{}", mutation_test_id),
+                seed: None,
             },
             MutantKrakenConfig::default(),
             output_directory,
             operators,
+            vec![],
             false,
         )
         .unwrap()
@@ -801,13 +1001,17 @@ mod tests {
         mutation_test_id: Uuid,
         output_directory: String,
     ) {
-        let fm = mutator
+        let mut fm = mutator
             .gather_mutations_per_file(&mut mutator.get_files_from_project().unwrap())
             .unwrap();
-        mutator.generate_mutations_per_file(&fm).unwrap();
-        // Check that the mutated files were created
+        mutator.generate_mutations_per_file(&mut fm).unwrap();
+        // Check that the mutated files were created, invalid mutants are never written
         for (file_name, fm) in fm {
-            for m in fm.mutations.clone() {
+            for m in fm
+                .mutations
+                .into_iter()
+                .filter(|m| m.result != MutationResult::Invalid)
+            {
                 let mutated_file_name =
                     get_mutated_file_name(&file_name, &m, output_directory.clone());
                 assert!(Path::new(mutated_file_name.to_str().unwrap()).exists());
@@ -822,24 +1026,31 @@ mod tests {
         mutation_test_id: Uuid,
         output_directory: String,
     ) {
-        let fm = mutator
+        let mut fm = mutator
             .gather_mutations_per_file(&mut mutator.get_files_from_project().unwrap())
             .unwrap();
-        mutator.generate_mutations_per_file(&fm).unwrap();
-        // Check that the mutated files were created
+        mutator.generate_mutations_per_file(&mut fm).unwrap();
+        // Check that the mutated files were created, invalid mutants are never written
         for (file_name, fm) in fm {
-            for m in fm.mutations {
+            for m in fm
+                .mutations
+                .into_iter()
+                .filter(|m| m.result != MutationResult::Invalid)
+            {
                 let mutated_file_name =
                     get_mutated_file_name(&file_name, &m, output_directory.clone());
                 let mut_file = fs::read_to_string(mutated_file_name)
                     .unwrap()
                     .as_bytes()
                     .to_vec();
-                let new_op_len = m.new_op.as_bytes().len();
+                let new_op_len = m.replacement().len();
                 let mut_range = m.start_byte..(m.start_byte + new_op_len);
                 // Checks that the mutated file does not have the same contents as the original file
                 // Print out strings
-                assert_eq!(m.new_op.as_bytes().to_vec(), mut_file[mut_range].to_vec());
+                assert_eq!(
+                    m.replacement().as_bytes().to_vec(),
+                    mut_file[mut_range].to_vec()
+                );
             }
         }
         // Remove contents in temp directory
@@ -1206,4 +1417,644 @@ mod tests {
         );
         assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
     }
+
+    #[test]
+    fn test_condition_negation_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CONDITION_NEGATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ConditionNegationOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_condition_negation_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CONDITION_NEGATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ConditionNegationOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_return_value_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_RETURN_VALUE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ReturnValueOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_return_value_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_RETURN_VALUE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ReturnValueOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_statement_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_STATEMENT_REMOVAL_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::StatementRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_statement_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_STATEMENT_REMOVAL_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::StatementRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_null_safety_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_NULL_SAFETY_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::NullSafetyOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_null_safety_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_NULL_SAFETY_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::NullSafetyOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_collection_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COLLECTION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CollectionOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_collection_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COLLECTION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CollectionOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_range_boundary_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_RANGE_BOUNDARY_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::RangeBoundaryOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_range_boundary_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_RANGE_BOUNDARY_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::RangeBoundaryOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_string_mutations_are_correct() {
+        let (mutation_test_id, output_directory) = create_temp_directory(KOTLIN_STRING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::StringOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_string_mutations_files_exist() {
+        let (mutation_test_id, output_directory) = create_temp_directory(KOTLIN_STRING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::StringOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_scope_function_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_SCOPE_FUNCTION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ScopeFunctionOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_scope_function_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_SCOPE_FUNCTION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ScopeFunctionOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_override_super_call_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::OverrideSuperCallOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_override_super_call_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::OverrideSuperCallOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_data_class_property_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::DataClassPropertyOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_data_class_property_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::DataClassPropertyOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_init_block_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::InitBlockRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_init_block_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CLASS_DECLARATION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::InitBlockRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_coroutine_builder_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CoroutineBuilderOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_coroutine_builder_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CoroutineBuilderOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_dispatcher_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::DispatcherOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_dispatcher_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::DispatcherOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_await_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::AwaitRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_await_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::AwaitRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_cancellation_check_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CancellationCheckRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_cancellation_check_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_COROUTINE_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::CancellationCheckRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_empty_catch_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::EmptyCatchOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_empty_catch_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::EmptyCatchOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_finally_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::FinallyRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_finally_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::FinallyRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_throw_removal_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ThrowRemovalOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_throw_removal_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::ThrowRemovalOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_run_catching_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::RunCatchingOperator],
+        );
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_run_catching_mutations_files_exist() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_EXCEPTION_HANDLING_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::RunCatchingOperator],
+        );
+        assert_all_mutation_files_were_created(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_mutations_json_records_seed() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_WHEN_EXPRESSION_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::WhenRemoveBranchOperator],
+        );
+        mutator.mutantkraken_config.general.seed = Some(42);
+        let fm = mutator
+            .gather_mutations_per_file(&mut mutator.get_files_from_project().unwrap())
+            .unwrap();
+        let mutations_directory = Path::new(&output_directory).join("mutations");
+        fs::create_dir_all(&mutations_directory).unwrap();
+        mutator.store_mutations(&fm).unwrap();
+
+        let mutations_json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(mutations_directory.join("mutations.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(mutations_json["seed"], 42);
+        assert_eq!(mutations_json["files"].as_object().unwrap().len(), 1);
+        remove_directory(mutation_test_id);
+    }
+
+    #[test]
+    fn test_when_branch_swap_mutations_are_correct() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_WHEN_STATEMENT_TEST_CODE);
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![MutationOperators::WhenRemoveBranchOperator],
+        );
+        mutator.mutantkraken_config.general.when_branch_mode = WhenBranchMode::AllBranchesAndSwaps;
+        assert_all_mutations_are_correct(&mut mutator, mutation_test_id, output_directory);
+    }
+
+    #[test]
+    fn test_custom_operator_mutations_are_reported_under_its_name() {
+        let (mutation_test_id, output_directory) =
+            create_temp_directory(KOTLIN_CONDITION_NEGATION_TEST_CODE);
+        fs::write(
+            format!("./{}/println.scm", mutation_test_id),
+            r#"((simple_identifier) @fn (#eq? @fn "println"))"#,
+        )
+        .unwrap();
+        let mut config = MutantKrakenConfig::default();
+        config.general.custom_operators = vec![CustomOperatorConfig {
+            name: "PrintlnToPrintOperator".into(),
+            query_file: "println.scm".into(),
+            replacement: "print".into(),
+            capture: None,
+        }];
+        let mut mutator = MutationTool::new(
+            MutationCommandConfig {
+                path: format!("./{}", mutation_test_id),
+                seed: None,
+            },
+            config,
+            output_directory.clone(),
+            vec![],
+            vec![],
+            false,
+        )
+        .unwrap();
+        let fm = mutator
+            .gather_mutations_per_file(&mut mutator.get_files_from_project().unwrap())
+            .unwrap();
+        let mutations = fm
+            .values()
+            .flat_map(|fm| fm.mutations.clone())
+            .collect::<Vec<_>>();
+        assert!(!mutations.is_empty());
+        assert!(mutations.iter().all(|m| m.old_op == "println"
+            && m.new_op == "print"
+            && m.mutation_type.to_string() == "PrintlnToPrintOperator"));
+
+        let mutations_directory = Path::new(&output_directory).join("mutations");
+        fs::create_dir_all(&mutations_directory).unwrap();
+        mutator.store_mutations(&fm).unwrap();
+        mutator.save_results(&mutations).unwrap();
+        let mutations_json: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(mutations_directory.join("mutations.json")).unwrap(),
+        )
+        .unwrap();
+        let file_mutations = mutations_json["files"].as_object().unwrap();
+        let first_mutation = &file_mutations.values().next().unwrap()["mutations"][0];
+        assert_eq!(first_mutation["mutation_type"], "PrintlnToPrintOperator");
+        let csv = fs::read_to_string(Path::new(&output_directory).join("output.csv")).unwrap();
+        assert_eq!(
+            csv.matches(",PrintlnToPrintOperator,").count(),
+            mutations.len()
+        );
+        remove_directory(mutation_test_id);
+    }
+
+    #[test]
+    fn test_mutations_are_assigned_to_their_gradle_module() {
+        let (mutation_test_id, output_directory) = create_temp_directory(KOTLIN_TEST_CODE);
+        let project_path = format!("./{}", mutation_test_id);
+        fs::write(
+            format!("{}/settings.gradle.kts", project_path),
+            "include(\":app\")",
+        )
+        .unwrap();
+        fs::create_dir_all(format!("{}/app/src", project_path)).unwrap();
+        fs::write(format!("{}/app/src/App.kt", project_path), KOTLIN_TEST_CODE).unwrap();
+        let mut mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory,
+            vec![MutationOperators::ArithmeticReplacementOperator],
+        );
+
+        let fm = mutator
+            .gather_mutations_per_file(&mut mutator.get_files_from_project().unwrap())
+            .unwrap();
+
+        let app_file = format!("{}/app/src/App.kt", project_path);
+        let root_file = format!("{}/{}.kt", project_path, mutation_test_id);
+        assert!(!fm[&app_file].mutations.is_empty());
+        assert!(fm[&app_file]
+            .mutations
+            .iter()
+            .all(|m| m.module.as_deref() == Some(":app")));
+        assert!(fm[&root_file].mutations.iter().all(|m| m.module.is_none()));
+        remove_directory(mutation_test_id);
+    }
+
+    /// Removes the directory of a test when it is dropped, even when the test fails
+    struct RemoveOnDrop(Uuid);
+
+    impl Drop for RemoveOnDrop {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(format!("./{}", self.0));
+        }
+    }
+
+    #[test]
+    fn test_mutations_that_do_not_parse_are_invalid() {
+        let (mutation_test_id, output_directory) = create_temp_directory(KOTLIN_TEST_CODE);
+        let _remove = RemoveOnDrop(mutation_test_id);
+        let mutator = create_mutator_with_specific_operators(
+            mutation_test_id,
+            output_directory.clone(),
+            vec![],
+        );
+        let file_name = format!("./{}/{}.kt", mutation_test_id, mutation_test_id);
+        let plus = KOTLIN_TEST_CODE.find(" + ").unwrap() + 1;
+        let open_paren = KOTLIN_TEST_CODE.find('(').unwrap();
+        let valid = Mutation::new(
+            plus,
+            plus + 1,
+            "-".into(),
+            "+".into(),
+            1,
+            MutationOperators::ArithmeticReplacementOperator,
+            file_name.clone(),
+        );
+        let invalid = Mutation::new(
+            open_paren,
+            open_paren + 1,
+            "{".into(),
+            "(".into(),
+            1,
+            MutationOperators::ArithmeticReplacementOperator,
+            file_name.clone(),
+        );
+        let mut fm = HashMap::from([(
+            file_name.clone(),
+            FileMutations {
+                mutations: vec![valid, invalid],
+            },
+        )]);
+        mutator.generate_mutations_per_file(&mut fm).unwrap();
+
+        let mutations = &fm[&file_name].mutations;
+        assert_eq!(mutations[0].result, MutationResult::InProgress);
+        assert!(
+            get_mutated_file_name(&file_name, &mutations[0], output_directory.clone()).exists()
+        );
+        assert_eq!(mutations[1].result, MutationResult::Invalid);
+        assert!(!get_mutated_file_name(&file_name, &mutations[1], output_directory).exists());
+    }
 }